@@ -0,0 +1,158 @@
+use std::fmt::Display;
+
+/// Whether allocations are being counted, which needs the `count-allocs` feature.
+pub const ENABLED: bool = cfg!(feature = "count-allocs");
+
+/// What one phase allocated on the thread that ran it.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Allocs {
+    pub count: u64,
+    /// Total bytes asked for, including memory freed again during the phase.
+    pub bytes: u64,
+    /// The most memory held at once above what was live when the phase began.
+    pub peak: u64,
+}
+
+/// Runs `f`, counting what it allocates when the counting allocator is built in.
+pub fn measure<T>(f: impl FnOnce() -> T) -> (T, Option<Allocs>) {
+    #[cfg(feature = "count-allocs")]
+    {
+        counting::reset();
+        let t = f();
+        (t, Some(counting::read()))
+    }
+
+    #[cfg(not(feature = "count-allocs"))]
+    (f(), None)
+}
+
+/// Formats a number of bytes with a binary unit, such as `1.50 KiB`.
+pub fn bytes(n: u64) -> String {
+    const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+
+    if n < 1024 {
+        return format!("{} B", n);
+    }
+
+    let mut size = n as f64 / 1024.0;
+    let mut unit = 0;
+    while size >= 1024.0 && unit < UNITS.len() - 1 {
+        size /= 1024.0;
+        unit += 1;
+    }
+
+    format!("{:.2} {}", size, UNITS[unit])
+}
+
+impl Display for Allocs {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} allocs, {}, peak {}", self.count, bytes(self.bytes), bytes(self.peak))
+    }
+}
+
+#[cfg(feature = "count-allocs")]
+mod counting {
+    use std::{alloc::{GlobalAlloc, Layout, System}, cell::Cell};
+
+    use super::Allocs;
+
+    /// The system allocator, with every allocation tallied against the thread
+    /// that made it, so days running in parallel don't see each other's.
+    struct Counting;
+
+    #[global_allocator]
+    static GLOBAL: Counting = Counting;
+
+    thread_local! {
+        static COUNT: Cell<u64> = const { Cell::new(0) };
+        static BYTES: Cell<u64> = const { Cell::new(0) };
+        /// Bytes held since the last reset. Freeing memory allocated before
+        /// then can take this below zero.
+        static LIVE: Cell<i64> = const { Cell::new(0) };
+        static PEAK: Cell<i64> = const { Cell::new(0) };
+    }
+
+    fn allocated(size: usize) {
+        // The counters may already be gone while the thread is shutting down
+        let _ = COUNT.try_with(|c| c.set(c.get() + 1));
+        let _ = BYTES.try_with(|b| b.set(b.get() + size as u64));
+        resize(size as i64);
+    }
+
+    fn resize(by: i64) {
+        let _ = LIVE.try_with(|live| {
+            live.set(live.get() + by);
+            let _ = PEAK.try_with(|peak| peak.set(peak.get().max(live.get())));
+        });
+    }
+
+    unsafe impl GlobalAlloc for Counting {
+        unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+            allocated(layout.size());
+            System.alloc(layout)
+        }
+
+        unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+            allocated(layout.size());
+            System.alloc_zeroed(layout)
+        }
+
+        unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+            resize(-(layout.size() as i64));
+            System.dealloc(ptr, layout)
+        }
+
+        unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+            // Growing counts as an allocation of the new size, as that's what it costs
+            let _ = COUNT.try_with(|c| c.set(c.get() + 1));
+            let _ = BYTES.try_with(|b| b.set(b.get() + new_size as u64));
+            resize(new_size as i64 - layout.size() as i64);
+            System.realloc(ptr, layout, new_size)
+        }
+    }
+
+    pub(super) fn reset() {
+        COUNT.with(|c| c.set(0));
+        BYTES.with(|b| b.set(0));
+        LIVE.with(|l| l.set(0));
+        PEAK.with(|p| p.set(0));
+    }
+
+    pub(super) fn read() -> Allocs {
+        Allocs{
+            count: COUNT.with(Cell::get),
+            bytes: BYTES.with(Cell::get),
+            peak: PEAK.with(Cell::get).max(0) as u64,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn format_bytes() {
+        assert_eq!(bytes(0), "0 B");
+        assert_eq!(bytes(1023), "1023 B");
+        assert_eq!(bytes(1536), "1.50 KiB");
+        assert_eq!(bytes(3 * 1024 * 1024), "3.00 MiB");
+    }
+
+    #[test]
+    fn measure_phase() {
+        let (v, allocs) = measure(|| {
+            let big: Vec<u8> = vec![0; 4096];
+            drop(big);
+            vec![1u8; 100]
+        });
+        assert_eq!(v.len(), 100);
+
+        assert_eq!(allocs.is_some(), ENABLED);
+        if let Some(allocs) = allocs {
+            assert_eq!(allocs.count, 2);
+            assert_eq!(allocs.bytes, 4196);
+            assert_eq!(allocs.peak, 4096);
+        }
+    }
+}
\ No newline at end of file
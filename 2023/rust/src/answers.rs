@@ -0,0 +1,170 @@
+use std::{collections::BTreeMap, fmt::Display, fs, io, path::Path, str::FromStr};
+
+use crate::solver::Answer;
+
+/// Known answers keyed by (year, day, part), stored one per line as
+/// `<year> <day> <part> <answer>`. Multi-line answers have their line breaks
+/// escaped so every answer fits on a single line.
+#[derive(Default)]
+pub struct Answers {
+    answers: BTreeMap<(u32, u32, u32), String>,
+}
+
+pub enum Status {
+    Pass,
+    /// The answer recorded for this part.
+    Fail(String),
+    Missing,
+}
+
+impl Answers {
+    /// Loads the answers file, treating a missing file as having no answers.
+    pub fn load(path: &Path) -> Result<Self, String> {
+        match fs::read_to_string(path) {
+            Ok(s) => s.parse().map_err(|e: ParseAnswersError| format!("{}: {}", path.display(), e.reason)),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Answers::default()),
+            Err(e) => Err(format!("{}: {}", path.display(), e)),
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), String> {
+        fs::write(path, self.to_string()).map_err(|e| format!("{}: {}", path.display(), e))
+    }
+
+    pub fn record(&mut self, year: u32, day: u32, part: u32, answer: &Answer) {
+        self.answers.insert((year, day, part), encode(answer));
+    }
+
+    pub fn check(&self, year: u32, day: u32, part: u32, answer: &Answer) -> Status {
+        match self.answers.get(&(year, day, part)) {
+            Some(expected) if *expected == encode(answer) => Status::Pass,
+            Some(expected) => Status::Fail(decode(expected)),
+            None => Status::Missing,
+        }
+    }
+}
+
+pub(crate) fn encode(answer: &Answer) -> String {
+    answer.to_string().replace('\\', "\\\\").replace('\n', "\\n")
+}
+
+pub(crate) fn decode(s: &str) -> String {
+    let mut decoded = String::new();
+
+    let mut chars = s.chars();
+    while let Some(c) = chars.next() {
+        match (c, chars.clone().next()) {
+            ('\\', Some('n')) => {
+                decoded.push('\n');
+                chars.next();
+            }
+            ('\\', Some('\\')) => {
+                decoded.push('\\');
+                chars.next();
+            }
+            (c, _) => decoded.push(c),
+        }
+    }
+
+    decoded
+}
+
+pub struct ParseAnswersError {
+    pub reason: String,
+}
+
+impl FromStr for Answers {
+    type Err = ParseAnswersError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut answers = BTreeMap::new();
+
+        for (i, line) in s.lines().enumerate() {
+            if line.is_empty() || line.starts_with('#') {
+                continue
+            }
+
+            let fields: Vec<&str> = line.splitn(4, ' ').collect();
+            if fields.len() != 4 {
+                return Err(ParseAnswersError{reason: format!("line {}: expected `<year> <day> <part> <answer>`", i+1)});
+            }
+
+            let mut key = [0; 3];
+            for (k, field) in key.iter_mut().zip(&fields[..3]) {
+                *k = match field.parse() {
+                    Ok(d) => d,
+                    Err(e) => return Err(ParseAnswersError{reason: format!("line {}: `{}`: {}", i+1, field, e)}),
+                };
+            }
+
+            answers.insert((key[0], key[1], key[2]), fields[3].to_string());
+        }
+
+        Ok(Answers{answers})
+    }
+}
+
+impl Display for Answers {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for ((year, day, part), answer) in &self.answers {
+            writeln!(f, "{} {} {} {}", year, day, part, answer)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn art() -> Answer {
+        Answer::Art(vec![String::from("#..#"), String::from(".##.")])
+    }
+
+    #[test]
+    fn encode_round_trip() {
+        assert_eq!(encode(&Answer::Int(-42)), "-42");
+        assert_eq!(encode(&Answer::Text(String::from("CMZ"))), "CMZ");
+        assert_eq!(encode(&art()), "#..#\\n.##.");
+        assert_eq!(encode(&Answer::Text(String::from("a\\nb"))), "a\\\\nb");
+
+        for answer in [Answer::Int(-42), Answer::Text(String::from("a\\nb")), art()] {
+            assert_eq!(decode(&encode(&answer)), answer.to_string());
+        }
+    }
+
+    #[test]
+    fn save_and_load() {
+        let mut answers = Answers::default();
+        answers.record(2022, 10, 2, &art());
+        answers.record(2022, 1, 1, &Answer::Int(24000));
+        answers.record(2022, 5, 1, &Answer::Text(String::from("CMZ")));
+
+        let text = answers.to_string();
+        assert_eq!(text, "2022 1 1 24000\n2022 5 1 CMZ\n2022 10 2 #..#\\n.##.\n");
+
+        let loaded: Answers = text.parse().ok().unwrap();
+        assert!(matches!(loaded.check(2022, 10, 2, &art()), Status::Pass));
+        assert!(matches!(loaded.check(2022, 5, 1, &Answer::Text(String::from("CMZ"))), Status::Pass));
+    }
+
+    #[test]
+    fn malformed_lines() {
+        let e = "# known answers\n2022 1 1 24000\n2022 1 2\n".parse::<Answers>().err().unwrap();
+        assert_eq!(e.reason, "line 3: expected `<year> <day> <part> <answer>`");
+
+        let e = "2022 1 1 24000\n\n2022 one 2 45000\n".parse::<Answers>().err().unwrap();
+        assert_eq!(e.reason, "line 3: `one`: invalid digit found in string");
+    }
+
+    #[test]
+    fn classify_answers() {
+        let answers: Answers = "2022 1 1 24000\n2022 10 2 #..#\\n.##.\n".parse().ok().unwrap();
+
+        assert!(matches!(answers.check(2022, 1, 1, &Answer::Int(24000)), Status::Pass));
+        assert!(matches!(answers.check(2022, 1, 1, &Answer::Int(1)), Status::Fail(expected) if expected == "24000"));
+        assert!(matches!(answers.check(2022, 10, 2, &Answer::Art(vec![String::from("####")])), Status::Fail(expected) if expected == "#..#\n.##."));
+        assert!(matches!(answers.check(2022, 1, 2, &Answer::Int(24000)), Status::Missing));
+    }
+}
\ No newline at end of file
@@ -0,0 +1,121 @@
+use std::{num::NonZeroUsize, time::{Duration, Instant}};
+
+use crate::{alloc::{self, Allocs}, error::Error, registry::{Day, Parsed}, solver::Answer};
+
+/// Timing statistics over repeated runs of the same phase.
+pub struct Stats {
+    pub runs: usize,
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    pub max: Duration,
+}
+
+impl Stats {
+    /// Summarises a non-empty set of samples.
+    pub fn from_samples(mut samples: Vec<Duration>) -> Self {
+        samples.sort();
+
+        let runs = samples.len();
+        let median = if runs.is_multiple_of(2) {
+            (samples[runs/2 - 1] + samples[runs/2]) / 2
+        } else {
+            samples[runs/2]
+        };
+
+        Stats{
+            runs,
+            min: samples[0],
+            median,
+            mean: samples.iter().sum::<Duration>() / runs as u32,
+            max: samples[runs-1],
+        }
+    }
+}
+
+pub struct ParseBench {
+    /// The last parse, for the parts to be solved from.
+    pub parsed: Box<dyn Parsed>,
+    pub parse: Stats,
+    /// What a single parse allocates, when allocations are counted.
+    pub allocs: Option<Allocs>,
+}
+
+pub struct PartBench {
+    pub part: u32,
+    pub answer: Answer,
+    pub solve: Stats,
+    /// What a single solve allocates, when allocations are counted.
+    pub allocs: Option<Allocs>,
+}
+
+/// Parses the input `runs` times, timing each run.
+pub fn bench_parse(day: &Day, input: &str, runs: NonZeroUsize) -> Result<ParseBench, Error> {
+    let mut samples = Vec::with_capacity(runs.get());
+    let mut last = None;
+
+    for _ in 0..runs.get() {
+        let start = Instant::now();
+        let (p, allocs) = alloc::measure(|| day.parse(input));
+        samples.push(start.elapsed());
+        last = Some((p?, allocs));
+    }
+
+    let (parsed, allocs) = last.expect("benchmarked at least once");
+    Ok(ParseBench{parsed, parse: Stats::from_samples(samples), allocs})
+}
+
+/// Solves a part from already parsed input `runs` times, timing each run.
+pub fn bench_part(day: &Day, part: u32, parsed: &dyn Parsed, runs: NonZeroUsize) -> Result<PartBench, Error> {
+    let mut samples = Vec::with_capacity(runs.get());
+    let mut last = None;
+
+    for _ in 0..runs.get() {
+        let start = Instant::now();
+        let (a, allocs) = alloc::measure(|| day.solve_parsed(part, parsed));
+        samples.push(start.elapsed());
+        last = Some((a?, allocs));
+    }
+
+    let (answer, allocs) = last.expect("benchmarked at least once");
+    Ok(PartBench{part, answer, solve: Stats::from_samples(samples), allocs})
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::years::CALENDAR;
+
+    use super::*;
+
+    fn millis(ms: &[u64]) -> Vec<Duration> {
+        ms.iter().map(|ms| Duration::from_millis(*ms)).collect()
+    }
+
+    #[test]
+    fn stats_odd_runs() {
+        let stats = Stats::from_samples(millis(&[5, 1, 3]));
+        assert_eq!(stats.runs, 3);
+        assert_eq!(stats.min, Duration::from_millis(1));
+        assert_eq!(stats.median, Duration::from_millis(3));
+        assert_eq!(stats.mean, Duration::from_millis(3));
+        assert_eq!(stats.max, Duration::from_millis(5));
+    }
+
+    #[test]
+    fn stats_even_runs() {
+        let stats = Stats::from_samples(millis(&[4, 1, 2, 9]));
+        assert_eq!(stats.median, Duration::from_millis(3));
+        assert_eq!(stats.mean, Duration::from_millis(4));
+    }
+
+    #[test]
+    fn runs_as_many_times_as_asked() {
+        let day = CALENDAR.day(2022, 1).ok().unwrap();
+        let runs = NonZeroUsize::new(3).unwrap();
+        let parsed = bench_parse(day, "1000\n2000\n\n3000\n", runs).ok().unwrap();
+        assert_eq!(parsed.parse.runs, 3);
+
+        let part = bench_part(day, 1, parsed.parsed.as_ref(), runs).ok().unwrap();
+        assert_eq!((part.answer, part.solve.runs), (Answer::Int(3000), 3));
+    }
+}
@@ -1,114 +1,999 @@
-use std::{fs, str::FromStr, io};
+use std::{fmt::Display, path::Path, str::FromStr};
 
-pub enum ArgType<T: Clone + FromStr> where <T as FromStr>::Err: std::fmt::Display {
+use log::LevelFilter;
+
+pub enum ArgType<T: Clone + FromStr + Display> where <T as FromStr>::Err: Display {
     Positional,
-    Optional(String, T),
+    /// A trailing positional that falls back to its default when omitted.
+    DefaultPositional(T),
+    Optional(Flags, T),
+    /// A flag without a value, set to the first value when given and the
+    /// second otherwise.
+    Switch(Flags, T, T),
+    /// Every positional left, joined with commas and parsed as one value.
+    List,
+    /// An option that can be given more than once, with every value joined
+    /// with commas and parsed as one. Falls back to its default when omitted.
+    Repeated(Flags, T),
+    /// A flag without a value, parsed from the number of times it's given.
+    Count(Flags),
+}
+
+/// What an argument is called and what it's for, as shown in the help.
+pub struct Info {
+    pub name: String,
+    pub help: String,
+}
+
+/// The short and long names an option goes by, such as `-d` and `--debug`.
+pub struct Flags {
+    pub short: Option<char>,
+    pub long: Option<String>,
+}
+
+impl Flags {
+    /// Reads flags written as they are typed, such as `-d, --debug` or `--example`.
+    pub fn new(spec: &str) -> Self {
+        let mut flags = Flags{short: None, long: None};
+        for flag in spec.split(',').map(str::trim) {
+            match (flag.strip_prefix("--"), flag.strip_prefix('-').map(|f| f.chars().collect::<Vec<char>>())) {
+                (Some(long), _) => flags.long = Some(long.to_string()),
+                (None, Some(short)) if short.len() == 1 => flags.short = Some(short[0]),
+                _ => panic!("`{}` is not a flag like -d or --debug", flag),
+            }
+        }
+        flags
+    }
+
+    fn matches(&self, arg: &str) -> bool {
+        match (arg.strip_prefix("--"), arg.strip_prefix('-')) {
+            (Some(long), _) => self.long.as_deref() == Some(long),
+            (None, Some(short)) => short.chars().count() == 1 && self.short == short.chars().next(),
+            _ => false,
+        }
+    }
+
+    /// The flag as written in usage lines and errors, preferring the short form.
+    fn first(&self) -> String {
+        match (self.short, &self.long) {
+            (Some(short), _) => format!("-{}", short),
+            (None, Some(long)) => format!("--{}", long),
+            (None, None) => String::new(),
+        }
+    }
+}
+
+impl Display for Flags {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match (self.short, &self.long) {
+            (Some(short), Some(long)) => write!(f, "-{}, --{}", short, long),
+            _ => write!(f, "{}", self.first()),
+        }
+    }
 }
 
-pub enum Arg<T: Clone + FromStr> where <T as FromStr>::Err: std::fmt::Display {
-    Unparsed(ArgType<T>),
+pub enum Arg<T: Clone + FromStr + Display> where <T as FromStr>::Err: Display {
+    Unparsed(Info, ArgType<T>),
     Parsed(T),
 }
 
-impl<T: Clone + FromStr> Arg<T> where <T as FromStr>::Err: std::fmt::Display {
-    pub fn new_positional() -> Self { Arg::Unparsed(ArgType::Positional) }
+impl<T: Clone + FromStr + Display> Arg<T> where <T as FromStr>::Err: Display {
+    pub fn new_positional(name: &str, help: &str) -> Self {
+        Arg::Unparsed(Info{name: name.to_string(), help: help.to_string()}, ArgType::Positional)
+    }
 
-    pub fn new_optional(flag: String, default: T) -> Self { Arg::Unparsed(ArgType::Optional(flag, default)) }
+    pub fn new_default_positional(name: &str, help: &str, default: T) -> Self {
+        Arg::Unparsed(Info{name: name.to_string(), help: help.to_string()}, ArgType::DefaultPositional(default))
+    }
+
+    /// An option given as `<flag> <name>` or `<flag>=<name>`, where `flags`
+    /// are written as typed, like `-y, --year`.
+    pub fn new_optional(flags: &str, name: &str, help: &str, default: T) -> Self {
+        Arg::Unparsed(Info{name: name.to_string(), help: help.to_string()}, ArgType::Optional(Flags::new(flags), default))
+    }
+
+    /// Takes every positional left, so it has to be the last one. `T` is
+    /// parsed from them joined with commas, so `1 2 5..9` reads as [`Numbers`].
+    pub fn new_list(name: &str, help: &str) -> Self {
+        Arg::Unparsed(Info{name: name.to_string(), help: help.to_string()}, ArgType::List)
+    }
+
+    /// An option that adds to its value each time it's given, like `-p 1 -p 2`.
+    pub fn new_repeated(flags: &str, name: &str, help: &str, default: T) -> Self {
+        Arg::Unparsed(Info{name: name.to_string(), help: help.to_string()}, ArgType::Repeated(Flags::new(flags), default))
+    }
+
+    /// A flag counted each time it's given, like `-vvv`, with `T` parsed from the count.
+    pub fn new_count(flags: &str, help: &str) -> Self {
+        Arg::Unparsed(Info{name: String::new(), help: help.to_string()}, ArgType::Count(Flags::new(flags)))
+    }
+
+    /// The parsed value, or an error if the argument was never given to a parser.
+    pub fn value(self) -> Result<T, ParseError> {
+        match self {
+            Arg::Parsed(t) => Ok(t),
+            arg => Err(ParseError{reason: format!("{} was never parsed", arg.usage())}),
+        }
+    }
+}
+
+impl Arg<bool> {
+    /// A flag that is true when given, like `-d, --debug`.
+    pub fn new_switch(flags: &str, help: &str) -> Self {
+        Arg::Unparsed(Info{name: String::new(), help: help.to_string()}, ArgType::Switch(Flags::new(flags), true, false))
+    }
 }
 
 trait ArgTrait {
+    /// Takes a positional's value from the front of `args`.
     fn parse(&mut self, args: &mut Vec<String>) -> Result<(), ParseError>;
+    /// Takes an option's flags and their values out of `tokens`.
+    fn parse_flags(&mut self, tokens: &mut Vec<Token>) -> Result<(), ParseError>;
+    /// The option's flags, and whether a value follows them.
+    fn flags(&self) -> Option<(&Flags, bool)>;
+    /// How the argument is written in the usage line.
+    fn usage(&self) -> String;
+    /// The argument's line in the help, split into how it's written and what it's for.
+    fn help(&self) -> (String, String);
 }
 
 pub struct ParseError {
     pub reason: String,
 }
 
-impl<T: Clone + FromStr> ArgTrait for Arg<T> where <T as FromStr>::Err: std::fmt::Display {
+/// An argument once the command line is expanded, with each flag paired
+/// with the value that follows it.
+enum Token {
+    /// A flag, and its value if it takes one and one was given.
+    Flag(String, Option<String>),
+    /// Anything that isn't a flag or a flag's value.
+    Value(String),
+}
+
+/// Why a command line didn't get as far as running a command.
+pub enum Stopped {
+    /// Help was asked for, and this is it.
+    Help(String),
+    /// The arguments were wrong. The reason includes the usage line.
+    Error(ParseError),
+}
+
+impl From<ParseError> for Stopped {
+    fn from(e: ParseError) -> Self {
+        Stopped::Error(e)
+    }
+}
+
+impl<T: Clone + FromStr + Display> ArgTrait for Arg<T> where <T as FromStr>::Err: Display {
     fn parse(&mut self, args: &mut Vec<String>) -> Result<(), ParseError> {
         match self {
-            Arg::Unparsed(arg_type) => match arg_type {
-                ArgType::Positional => *self = Arg::Parsed(match args.remove(0).parse() {
-                    Ok(t) => t,
-                    Err(e) => return Err(ParseError{reason: format!("failed to parse positional argument: {}", e)}),
-                }),
-                ArgType::Optional(flag, def) => match args.iter().position(|t| *t == format!("-{}", flag)) {
-                    Some(t) => {
-                        *self = Arg::Parsed(match args.remove(t+1).parse() {
+            Arg::Unparsed(info, arg_type) => match arg_type {
+                ArgType::Positional => {
+                    if args.is_empty() {
+                        return Err(ParseError{reason: format!("missing positional argument <{}>", info.name)});
+                    }
+                    *self = Arg::Parsed(match args.remove(0).parse() {
+                        Ok(t) => t,
+                        Err(e) => return Err(ParseError{reason: format!("failed to parse positional argument <{}>: {}", info.name, e)}),
+                    })
+                }
+                ArgType::DefaultPositional(def) => {
+                    if args.is_empty() {
+                        *self = Arg::Parsed(def.clone());
+                        return Ok(());
+                    }
+                    *self = Arg::Parsed(match args.remove(0).parse() {
+                        Ok(t) => t,
+                        Err(e) => return Err(ParseError{reason: format!("failed to parse positional argument <{}>: {}", info.name, e)}),
+                    })
+                }
+                ArgType::List => {
+                    if args.is_empty() {
+                        return Err(ParseError{reason: format!("missing positional argument <{}>", info.name)});
+                    }
+                    *self = Arg::Parsed(match std::mem::take(args).join(",").parse() {
+                        Ok(t) => t,
+                        Err(e) => return Err(ParseError{reason: format!("failed to parse positional argument <{}>: {}", info.name, e)}),
+                    })
+                }
+                ArgType::Optional(..) | ArgType::Switch(..) | ArgType::Repeated(..) | ArgType::Count(..) => unreachable!("options are parsed from flags"),
+            }
+            Arg::Parsed(_) => (),
+        };
+
+        Ok(())
+    }
+
+    fn parse_flags(&mut self, tokens: &mut Vec<Token>) -> Result<(), ParseError> {
+        match self {
+            Arg::Unparsed(info, arg_type) => match arg_type {
+                ArgType::Optional(flags, def) => match take(flags, tokens).as_slice() {
+                    [] => *self = Arg::Parsed(def.clone()),
+                    [_, (flag, _), ..] => return Err(ParseError{reason: format!("{} given more than once", flag)}),
+                    [(flag, None)] => return Err(ParseError{reason: format!("missing value for {} <{}>", flag, info.name)}),
+                    [(flag, Some(value))] => *self = Arg::Parsed(match value.parse() {
+                        Ok(t) => t,
+                        Err(e) => return Err(ParseError{reason: format!("failed to parse optional argument {}: {}", flag, e)}),
+                    }),
+                }
+                ArgType::Switch(flags, on, off) => match take(flags, tokens).as_slice() {
+                    [] => *self = Arg::Parsed(off.clone()),
+                    [_, (flag, _), ..] => return Err(ParseError{reason: format!("{} given more than once", flag)}),
+                    [(flag, Some(_))] => return Err(ParseError{reason: format!("{} doesn't take a value", flag)}),
+                    [(_, None)] => *self = Arg::Parsed(on.clone()),
+                }
+                ArgType::Repeated(flags, def) => {
+                    let mut values = Vec::new();
+                    for (flag, value) in take(flags, tokens) {
+                        match value {
+                            Some(value) => values.push(value),
+                            None => return Err(ParseError{reason: format!("missing value for {} <{}>", flag, info.name)}),
+                        }
+                    }
+                    *self = Arg::Parsed(match values.is_empty() {
+                        true => def.clone(),
+                        false => match values.join(",").parse() {
                             Ok(t) => t,
-                            Err(e) => return Err(ParseError{reason: format!("failed to parse optional argument: {}", e)}),
-                        });
-                        args.remove(t);
+                            Err(e) => return Err(ParseError{reason: format!("failed to parse optional argument {}: {}", flags.first(), e)}),
+                        }
+                    })
+                }
+                ArgType::Count(flags) => {
+                    let given = take(flags, tokens);
+                    if let Some((flag, _)) = given.iter().find(|(_, value)| value.is_some()) {
+                        return Err(ParseError{reason: format!("{} doesn't take a value", flag)});
                     }
-                    None => *self = Arg::Parsed(def.clone()),
+                    *self = Arg::Parsed(match given.len().to_string().parse() {
+                        Ok(t) => t,
+                        Err(e) => return Err(ParseError{reason: format!("{} given {} times: {}", flags.first(), given.len(), e)}),
+                    })
                 }
+                ArgType::Positional | ArgType::DefaultPositional(_) | ArgType::List => unreachable!("positionals are parsed from values"),
             }
             Arg::Parsed(_) => (),
         };
 
         Ok(())
     }
+
+    fn flags(&self) -> Option<(&Flags, bool)> {
+        match self {
+            Arg::Unparsed(_, ArgType::Optional(flags, _)) => Some((flags, true)),
+            Arg::Unparsed(_, ArgType::Switch(flags, _, _) | ArgType::Count(flags)) => Some((flags, false)),
+            Arg::Unparsed(_, ArgType::Repeated(flags, _)) => Some((flags, true)),
+            _ => None,
+        }
+    }
+
+    fn usage(&self) -> String {
+        match self {
+            Arg::Unparsed(info, ArgType::Positional) => format!("<{}>", info.name),
+            Arg::Unparsed(info, ArgType::DefaultPositional(_)) => format!("[{}]", info.name),
+            Arg::Unparsed(info, ArgType::Optional(flags, _)) => format!("[{} <{}>]", flags.first(), info.name),
+            Arg::Unparsed(_, ArgType::Switch(flags, _, _)) => format!("[{}]", flags.first()),
+            Arg::Unparsed(info, ArgType::List) => format!("<{}>...", info.name),
+            Arg::Unparsed(info, ArgType::Repeated(flags, _)) => format!("[{} <{}>]...", flags.first(), info.name),
+            Arg::Unparsed(_, ArgType::Count(flags)) => format!("[{}]...", flags.first()),
+            Arg::Parsed(_) => String::new(),
+        }
+    }
+
+    fn help(&self) -> (String, String) {
+        match self {
+            Arg::Unparsed(info, ArgType::Positional) => (self.usage(), info.help.clone()),
+            Arg::Unparsed(info, ArgType::DefaultPositional(def)) => (self.usage(), with_default(&info.help, def)),
+            Arg::Unparsed(info, ArgType::Optional(flags, def)) => (format!("{} <{}>", flags, info.name), with_default(&info.help, def)),
+            Arg::Unparsed(info, ArgType::Switch(flags, _, _) | ArgType::Count(flags)) => (flags.to_string(), info.help.clone()),
+            Arg::Unparsed(info, ArgType::List) => (self.usage(), info.help.clone()),
+            Arg::Unparsed(info, ArgType::Repeated(flags, def)) => (format!("{} <{}>", flags, info.name), with_default(&info.help, def)),
+            Arg::Parsed(_) => (String::new(), String::new()),
+        }
+    }
+}
+
+/// Takes every use of an option out of `tokens`, in the order given.
+fn take(flags: &Flags, tokens: &mut Vec<Token>) -> Vec<(String, Option<String>)> {
+    let mut taken = Vec::new();
+    tokens.retain_mut(|token| match token {
+        Token::Flag(flag, value) if flags.matches(flag) => {
+            taken.push((std::mem::take(flag), value.take()));
+            false
+        }
+        _ => true,
+    });
+    taken
+}
+
+fn with_default(help: &str, default: &impl Display) -> String {
+    match default.to_string().as_str() {
+        "" => help.to_string(),
+        default => format!("{} (default: {})", help, default),
+    }
+}
+
+/// Whether an argument is written like a flag. A lone `-` is a value, as are
+/// negative numbers.
+fn is_flag(arg: &str) -> bool {
+    arg.starts_with('-') && arg.chars().nth(1).is_some_and(|c| !c.is_ascii_digit())
+}
+
+/// Lines up the second column of a help section.
+fn columns(rows: &[(String, String)]) -> String {
+    let width = rows.iter().map(|(left, _)| left.len()).max().unwrap_or_default();
+    rows.iter()
+        .map(|(left, right)| format!("  {:<width$}  {}", left, right, width = width).trim_end().to_string())
+        .collect::<Vec<String>>()
+        .join("\n")
 }
 
-pub struct FileContents{
-    pub contents: String,
+/// The name the program was run as, for usage lines.
+fn program(args: &[String]) -> String {
+    args.first()
+        .and_then(|arg| Path::new(arg).file_name())
+        .map(|name| name.to_string_lossy().to_string())
+        .unwrap_or_default()
 }
 
-impl FromStr for FileContents {
-    type Err = io::Error;
+fn is_help(arg: &str) -> bool {
+    arg == "-h" || arg == "--help"
+}
+
+/// Numbers written one by one or as inclusive ranges, separated by commas,
+/// such as `1,2,5..9`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Numbers(pub Vec<u32>);
 
-    fn from_str(value: &str) -> Result<Self, Self::Err> {
-        match fs::read_to_string(value) {
-            Ok(s) => Ok(FileContents{contents: s}),
-            Err(e) => Err(e),
+impl Numbers {
+    /// The furthest a range can reach, the last day of the calendar.
+    pub const RANGE_END: u32 = 25;
+}
+
+#[derive(Debug)]
+pub struct ParseNumbersError {
+    pub reason: String,
+}
+
+impl Display for ParseNumbersError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.reason)
+    }
+}
+
+impl FromStr for Numbers {
+    type Err = ParseNumbersError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let number = |n: &str| n.parse::<u32>().map_err(|e| ParseNumbersError{reason: format!("`{}`: {}", n, e)});
+
+        let mut numbers = Vec::new();
+        for item in s.split(',') {
+            match item.split_once("..") {
+                Some((from, to)) => {
+                    let (from, to) = (number(from)?, number(to)?);
+                    if from > to {
+                        return Err(ParseNumbersError{reason: format!("`{}` counts down", item)});
+                    }
+                    if to > Numbers::RANGE_END {
+                        return Err(ParseNumbersError{reason: format!("`{}` goes past {}", item, Numbers::RANGE_END)});
+                    }
+                    numbers.extend(from..=to);
+                }
+                None => numbers.push(number(item)?),
+            }
         }
+
+        Ok(Numbers(numbers))
     }
 }
 
-impl Clone for FileContents {
-    fn clone(&self) -> Self {
-        FileContents{contents: self.contents.clone()}
+impl Display for Numbers {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let numbers: Vec<String> = self.0.iter().map(u32::to_string).collect();
+        write!(f, "{}", numbers.join(","))
+    }
+}
+
+/// How much to log, from the number of times a flag like `-v` is given:
+/// info by default, then debug, then everything.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Verbosity(pub LevelFilter);
+
+impl FromStr for Verbosity {
+    type Err = std::num::ParseIntError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Verbosity(match s.parse::<u32>()? {
+            0 => LevelFilter::Info,
+            1 => LevelFilter::Debug,
+            _ => LevelFilter::Trace,
+        }))
+    }
+}
+
+impl Display for Verbosity {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0.as_str().to_lowercase())
+    }
+}
+
+/// An option's value that tells being left out apart from being given,
+/// even as nothing at all like `--input-text=`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Given<T>(pub Option<T>);
+
+impl<T: FromStr> FromStr for Given<T> {
+    type Err = T::Err;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Given(Some(s.parse()?)))
+    }
+}
+
+impl<T: Display> Display for Given<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.0 {
+            Some(t) => write!(f, "{}", t),
+            None => Ok(()),
+        }
     }
 }
 
 pub struct Parser<'a> {
-    positionals: Vec<Box<&'a mut dyn ArgTrait>>,
-    optionals: Vec<Box<&'a mut dyn ArgTrait>>,
+    /// The subcommand these arguments belong to, which prefixes any errors.
+    command: Option<String>,
+    positionals: Vec<&'a mut dyn ArgTrait>,
+    optionals: Vec<&'a mut dyn ArgTrait>,
+}
+
+impl<'a> Default for Parser<'a> {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl<'a> Parser<'a> {
     pub fn new() -> Self {
-        Parser{positionals: Vec::new(), optionals: Vec::new()}
+        Parser{command: None, positionals: Vec::new(), optionals: Vec::new()}
+    }
+
+    /// A parser for the arguments of one subcommand, named in its errors.
+    pub fn for_subcommand(command: &str) -> Self {
+        Parser{command: Some(command.to_string()), positionals: Vec::new(), optionals: Vec::new()}
     }
 
-    pub fn add_argument <T: Clone + FromStr>(&mut self, arg: &'a mut Arg<T>) where <T as FromStr>::Err: std::fmt::Display {
+    pub fn add_argument <T: Clone + FromStr + Display>(&mut self, arg: &'a mut Arg<T>) where <T as FromStr>::Err: Display {
         match arg {
-            Arg::Unparsed(arg_type) => match arg_type {
-                ArgType::Positional => self.positionals.push(Box::new(arg)),
-                ArgType::Optional(_, _) => self.optionals.push(Box::new(arg)),
+            Arg::Unparsed(_, arg_type) => match arg_type {
+                ArgType::Positional | ArgType::DefaultPositional(_) | ArgType::List => self.positionals.push(arg),
+                ArgType::Optional(_, _) | ArgType::Switch(_, _, _) | ArgType::Repeated(_, _) | ArgType::Count(_) => self.optionals.push(arg),
             }
             Arg::Parsed(_) => ()
         }
         
     }
 
-    pub fn parse(self, mut args: Vec<String>) -> Result<(), ParseError> {
+    /// The usage line, such as `usage: aoc bench [-n <runs>] <day> [part]`.
+    pub fn usage(&self, program: &str) -> String {
+        let mut usage = format!("usage: {}", program);
+        if let Some(command) = &self.command {
+            usage.push_str(&format!(" {}", command));
+        }
+        for arg in self.optionals.iter().chain(self.positionals.iter()) {
+            usage.push_str(&format!(" {}", arg.usage()));
+        }
+        usage
+    }
+
+    /// The usage line followed by what each argument is for.
+    pub fn help(&self, program: &str) -> String {
+        let mut help = self.usage(program);
+
+        let arguments: Vec<(String, String)> = self.positionals.iter().map(|arg| arg.help()).collect();
+        if !arguments.is_empty() {
+            help.push_str(&format!("\n\narguments:\n{}", columns(&arguments)));
+        }
+
+        let mut options: Vec<(String, String)> = self.optionals.iter().map(|arg| arg.help()).collect();
+        options.push((String::from("-h, --help"), String::from("show this help")));
+        help.push_str(&format!("\n\noptions:\n{}", columns(&options)));
+
+        help
+    }
+
+    /// Parses `args`, the first of which is the program name. Asking for help
+    /// stops with the help, and any failure gives the usage line along with
+    /// what went wrong.
+    pub fn parse(self, mut args: Vec<String>) -> Result<(), Stopped> {
+        let program = program(&args);
+        let usage = self.usage(&program);
+        let command = self.command.clone();
+        let failed = |e: ParseError| match &command {
+            Some(command) => Stopped::Error(ParseError{reason: format!("{}: {}\n{}", command, e.reason, usage)}),
+            None => Stopped::Error(ParseError{reason: format!("{}\n{}", e.reason, usage)}),
+        };
+
         // Remove the filename as the first argument
         args.remove(0);
 
+        // Everything after `--` is positional, even if it looks like a flag
+        let rest = match args.iter().position(|arg| arg == "--") {
+            Some(i) => {
+                let rest = args.split_off(i+1);
+                args.pop();
+                rest
+            }
+            None => Vec::new(),
+        };
+        let tokens = self.expand(args).map_err(failed)?;
+
+        // Help is only asked for by a flag, not by another flag's value
+        if tokens.iter().any(|token| matches!(token, Token::Flag(flag, _) if is_help(flag))) {
+            return Err(Stopped::Help(self.help(&program)));
+        }
+
+        self.parse_tokens(tokens, rest).map_err(failed)
+    }
+
+    fn parse_tokens(self, mut tokens: Vec<Token>, rest: Vec<String>) -> Result<(), ParseError> {
         // Parse optionals first, leaving only positionals behind
         for arg in self.optionals {
-            arg.parse(&mut args)?;
+            arg.parse_flags(&mut tokens)?;
         }
 
+        let mut args = Vec::new();
+        for token in tokens {
+            match token {
+                Token::Flag(flag, _) => return Err(ParseError{reason: format!("unknown option {}", flag)}),
+                Token::Value(value) => args.push(value),
+            }
+        }
+        args.extend(rest);
+
         for arg in self.positionals {
             arg.parse(&mut args)?;
         }
 
-        if args.len() > 0 {
+        if !args.is_empty() {
             return Err(ParseError { reason: format!("unparsed arguments: {:?}", args) })
         }
 
         Ok(())
     }
+
+    /// Pairs each flag that takes a value with the argument after it, which is
+    /// kept as it is even if it starts with a dash. `--flag=value` is split
+    /// into the flag and its value, and bundled short flags like `-dv` into
+    /// `-d -v`. A bundled flag that takes a value takes the rest of the
+    /// bundle, so `-n5` is `-n 5`.
+    fn expand(&self, args: Vec<String>) -> Result<Vec<Token>, ParseError> {
+        let flags: Vec<(&Flags, bool)> = self.optionals.iter().filter_map(|arg| arg.flags()).collect();
+        let takes_value = |arg: &str| flags.iter().any(|(flags, value)| *value && flags.matches(arg));
+        let mut tokens = Vec::new();
+        let mut value_next = false;
+
+        for arg in args {
+            if value_next {
+                value_next = false;
+                if let Some(Token::Flag(_, value)) = tokens.last_mut() {
+                    *value = Some(arg);
+                }
+                continue
+            }
+
+            if let Some((flag, value)) = arg.strip_prefix("--").and_then(|long| long.split_once('=')) {
+                tokens.push(Token::Flag(format!("--{}", flag), Some(value.to_string())));
+                continue
+            }
+
+            let bundle = match arg.strip_prefix('-') {
+                Some(bundle) if is_flag(&arg) && !arg.starts_with("--") && bundle.chars().count() > 1 => bundle,
+                _ if is_flag(&arg) => {
+                    value_next = takes_value(&arg);
+                    tokens.push(Token::Flag(arg, None));
+                    continue
+                }
+                _ => {
+                    tokens.push(Token::Value(arg));
+                    continue
+                }
+            };
+
+            for (i, c) in bundle.char_indices() {
+                match flags.iter().find(|(flags, _)| flags.short == Some(c)) {
+                    Some((_, true)) => {
+                        let value = &bundle[i + c.len_utf8()..];
+                        match value {
+                            "" => {
+                                value_next = true;
+                                tokens.push(Token::Flag(format!("-{}", c), None));
+                            }
+                            value => tokens.push(Token::Flag(format!("-{}", c), Some(value.to_string()))),
+                        }
+                        break
+                    }
+                    Some((_, false)) => tokens.push(Token::Flag(format!("-{}", c), None)),
+                    None => return Err(ParseError{reason: format!("unknown option -{} in {}", c, arg)}),
+                }
+            }
+        }
+
+        Ok(tokens)
+    }
+}
+
+/// Declares a struct holding a command's parsed arguments, with a `parse`
+/// that fills it in from the command line. Each field names the [`Arg`]
+/// constructor that parses it, and positionals are taken in field order.
+///
+/// ```text
+/// cli::arguments! {
+///     struct BenchArgs for "bench" {
+///         runs: usize = new_optional("-n, --runs", "runs", "times to run each phase", 10),
+///         day: u32 = new_positional("day", "day to benchmark"),
+///     }
+/// }
+/// ```
+#[macro_export]
+macro_rules! arguments {
+    ($(#[$meta:meta])* $vis:vis struct $name:ident for $command:literal {
+        $($field:ident: $type:ty = $constructor:ident($($arg:expr),* $(,)?)),* $(,)?
+    }) => {
+        $(#[$meta])*
+        $vis struct $name {
+            $(pub $field: $type,)*
+        }
+
+        impl $name {
+            pub fn parse(args: Vec<String>) -> Result<Self, $crate::cli::Stopped> {
+                $(let mut $field = $crate::cli::Arg::<$type>::$constructor($($arg),*);)*
+
+                let mut parser = $crate::cli::Parser::for_subcommand($command);
+                $(parser.add_argument(&mut $field);)*
+                parser.parse(args)?;
+
+                Ok($name{$($field: $field.value()?,)*})
+            }
+        }
+    };
+}
+
+pub use arguments;
+
+/// Runs a subcommand with the arguments that follow its name.
+type Run = fn(Vec<String>);
+
+/// Subcommands chosen by the first argument, each parsing the rest of the
+/// arguments with its own [`Parser`].
+#[derive(Default)]
+pub struct Subcommands {
+    /// Each subcommand's name, what it does, and how to run it.
+    commands: Vec<(&'static str, &'static str, Run)>,
+    /// Run when the first argument isn't a subcommand, with every argument.
+    default: Option<Run>,
+}
+
+impl Subcommands {
+    pub fn new() -> Self {
+        Subcommands{commands: Vec::new(), default: None}
+    }
+
+    pub fn add(&mut self, name: &'static str, about: &'static str, run: Run) {
+        self.commands.push((name, about, run));
+    }
+
+    /// Makes a subcommand run when no subcommand is named, so `<program> 1 2`
+    /// is `<program> run 1 2`.
+    pub fn set_default(&mut self, name: &'static str) {
+        self.default = self.commands.iter().find(|(n, _, _)| *n == name).map(|(_, _, run)| *run);
+    }
+
+    pub fn names(&self) -> impl Iterator<Item = &'static str> + '_ {
+        self.commands.iter().map(|(name, _, _)| *name)
+    }
+
+    /// Lists the subcommands and what each of them does.
+    pub fn help(&self, program: &str) -> String {
+        let commands: Vec<(String, String)> = self.commands.iter().map(|(name, about, _)| (name.to_string(), about.to_string())).collect();
+        format!(
+            "usage: {} <command> [arguments]\n\ncommands:\n{}\n\nrun `{} <command> --help` for a command's arguments",
+            program,
+            columns(&commands),
+            program,
+        )
+    }
+
+    /// Runs the subcommand named by the first argument after the program name,
+    /// handing it the program name followed by the remaining arguments.
+    pub fn dispatch(&self, mut args: Vec<String>) -> Result<(), Stopped> {
+        let program = program(&args);
+        if args.get(1).is_some_and(|arg| is_help(arg)) {
+            return Err(Stopped::Help(self.help(&program)));
+        }
+
+        let command = args.get(1).and_then(|name| self.commands.iter().find(|(n, _, _)| n == name));
+        match (command, self.default) {
+            (Some((_, _, run)), _) => {
+                args.remove(1);
+                run(args);
+                Ok(())
+            }
+            (None, Some(run)) => {
+                run(args);
+                Ok(())
+            }
+            (None, None) => {
+                let names: Vec<&str> = self.names().collect();
+                let reason = match args.get(1) {
+                    Some(name) => format!("unknown subcommand `{}` (expected one of: {})", name, names.join(", ")),
+                    None => format!("missing subcommand (expected one of: {})", names.join(", ")),
+                };
+                Err(Stopped::Error(ParseError{reason: format!("{}\nusage: {} <command> [arguments]", reason, program)}))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+
+    use super::*;
+
+    fn args(args: &[&str]) -> Vec<String> {
+        args.iter().map(|a| a.to_string()).collect()
+    }
+
+    fn error<T>(result: Result<T, Stopped>) -> String {
+        match result {
+            Err(Stopped::Error(e)) => e.reason,
+            Err(Stopped::Help(help)) => panic!("expected an error, got help:\n{}", help),
+            Ok(_) => panic!("expected an error"),
+        }
+    }
+
+    fn help<T>(result: Result<T, Stopped>) -> String {
+        match result {
+            Err(Stopped::Help(help)) => help,
+            Err(Stopped::Error(e)) => panic!("expected help, got an error:\n{}", e.reason),
+            Ok(_) => panic!("expected help"),
+        }
+    }
+
+    thread_local! {
+        static RAN: RefCell<Vec<String>> = const { RefCell::new(Vec::new()) };
+    }
+
+    fn record(name: &str, args: Vec<String>) {
+        RAN.with(|r| r.borrow_mut().push(format!("{} {}", name, args[1..].join(" "))));
+    }
+
+    fn subcommands() -> Subcommands {
+        let mut commands = Subcommands::new();
+        commands.add("run", "run a part", |args| record("run", args));
+        commands.add("bench", "time a day", |args| record("bench", args));
+        commands
+    }
+
+    #[test]
+    fn dispatch_subcommands() {
+        let mut commands = subcommands();
+        commands.dispatch(args(&["aoc", "bench", "1", "-n", "5"])).ok().unwrap();
+
+        let e = error(commands.dispatch(args(&["aoc", "1", "2"])));
+        assert_eq!(e, "unknown subcommand `1` (expected one of: run, bench)\nusage: aoc <command> [arguments]");
+        let e = error(commands.dispatch(args(&["aoc"])));
+        assert_eq!(e, "missing subcommand (expected one of: run, bench)\nusage: aoc <command> [arguments]");
+
+        commands.set_default("run");
+        commands.dispatch(args(&["aoc", "1", "2"])).ok().unwrap();
+
+        assert_eq!(RAN.with(|r| r.take()), vec!["bench 1 -n 5", "run 1 2"]);
+    }
+
+    #[test]
+    fn subcommand_help() {
+        let e = help(subcommands().dispatch(args(&["/bin/aoc", "--help"])));
+        assert_eq!(e, "usage: aoc <command> [arguments]\n\ncommands:\n  run    run a part\n  bench  time a day\n\nrun `aoc <command> --help` for a command's arguments");
+    }
+
+    #[test]
+    fn subcommand_errors() {
+        let mut day: Arg<u32> = Arg::new_positional("day", "day to run");
+        let mut parser = Parser::for_subcommand("bench");
+        parser.add_argument(&mut day);
+        assert_eq!(error(parser.parse(args(&["aoc"]))), "bench: missing positional argument <day>\nusage: aoc bench <day>");
+
+        let mut day: Arg<u32> = Arg::new_positional("day", "day to run");
+        let mut parser = Parser::new();
+        parser.add_argument(&mut day);
+        assert_eq!(error(parser.parse(args(&["aoc", "1", "2"]))), "unparsed arguments: [\"2\"]\nusage: aoc <day>");
+    }
+
+    #[test]
+    fn generated_help() {
+        let mut year: Arg<u32> = Arg::new_optional("-y, --year", "year", "puzzle year", 2022);
+        let mut inputs: Arg<String> = Arg::new_optional("--inputs", "dir", "where inputs are kept", String::from("inputs"));
+        let mut debug: Arg<bool> = Arg::new_switch("-d, --debug", "log debugging output");
+        let mut day: Arg<u32> = Arg::new_positional("day", "day to run");
+        let mut file: Arg<String> = Arg::new_default_positional("file", "input file", String::new());
+
+        let mut parser = Parser::for_subcommand("run");
+        parser.add_argument(&mut year);
+        parser.add_argument(&mut inputs);
+        parser.add_argument(&mut debug);
+        parser.add_argument(&mut day);
+        parser.add_argument(&mut file);
+
+        assert_eq!(help(parser.parse(args(&["aoc", "1", "-h"]))), "\
+usage: aoc run [-y <year>] [--inputs <dir>] [-d] <day> [file]
+
+arguments:
+  <day>   day to run
+  [file]  input file
+
+options:
+  -y, --year <year>  puzzle year (default: 2022)
+  --inputs <dir>     where inputs are kept (default: inputs)
+  -d, --debug        log debugging output
+  -h, --help         show this help");
+    }
+
+    /// Parses `argv` with a year, a run count, two switches and a file.
+    fn parse(argv: &[&str]) -> Result<(u32, u32, bool, bool, String), String> {
+        let mut year: Arg<u32> = Arg::new_optional("-y, --year", "year", "puzzle year", 2022);
+        let mut runs: Arg<u32> = Arg::new_optional("-n", "runs", "times to run", 1);
+        let mut debug: Arg<bool> = Arg::new_switch("-d, --debug", "log debugging output");
+        let mut verbose: Arg<bool> = Arg::new_switch("-v", "say more");
+        let mut file: Arg<String> = Arg::new_default_positional("file", "input file", String::new());
+
+        let mut parser = Parser::new();
+        parser.add_argument(&mut year);
+        parser.add_argument(&mut runs);
+        parser.add_argument(&mut debug);
+        parser.add_argument(&mut verbose);
+        parser.add_argument(&mut file);
+        let parsed = parser.parse(args(argv));
+        if parsed.is_err() {
+            return Err(error(parsed));
+        }
+
+        match (year, runs, debug, verbose, file) {
+            (Arg::Parsed(year), Arg::Parsed(runs), Arg::Parsed(debug), Arg::Parsed(verbose), Arg::Parsed(file)) => Ok((year, runs, debug, verbose, file)),
+            _ => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn switches_and_long_flags() {
+        assert_eq!(parse(&["aoc"]), Ok((2022, 1, false, false, String::new())));
+        assert_eq!(parse(&["aoc", "-d", "a.txt"]), Ok((2022, 1, true, false, String::from("a.txt"))));
+        assert_eq!(parse(&["aoc", "--debug", "--year", "2021"]), Ok((2021, 1, true, false, String::new())));
+        assert_eq!(parse(&["aoc", "-d", "-y"]).unwrap_err(), "missing value for -y <year>\nusage: aoc [-y <year>] [-n <runs>] [-d] [-v] [file]");
+        assert_eq!(parse(&["aoc", "-d", "--debug"]).unwrap_err().lines().next(), Some("--debug given more than once"));
+        assert_eq!(parse(&["aoc", "--verbose"]).unwrap_err().lines().next(), Some("unknown option --verbose"));
+    }
+
+    #[test]
+    fn bundled_flags() {
+        assert_eq!(parse(&["aoc", "-dv"]), Ok((2022, 1, true, true, String::new())));
+        assert_eq!(parse(&["aoc", "-vn5"]), Ok((2022, 5, false, true, String::new())));
+        assert_eq!(parse(&["aoc", "-dy", "2019"]), Ok((2019, 1, true, false, String::new())));
+        assert_eq!(parse(&["aoc", "-dx"]).unwrap_err().lines().next(), Some("unknown option -x in -dx"));
+    }
+
+    #[test]
+    fn values_after_separator() {
+        assert_eq!(parse(&["aoc", "-", "-d"]), Ok((2022, 1, true, false, String::from("-"))));
+        assert_eq!(parse(&["aoc", "-d", "--", "-v"]), Ok((2022, 1, true, false, String::from("-v"))));
+        assert_eq!(parse(&["aoc", "--", "--year=1"]), Ok((2022, 1, false, false, String::from("--year=1"))));
+        assert_eq!(parse(&["aoc", "-y", "-d"]).unwrap_err().lines().next(), Some("failed to parse optional argument -y: invalid digit found in string"));
+    }
+
+    /// Parses `argv` with a year, a file and a count, for a day.
+    fn parse_run(argv: &[&str]) -> Result<(u32, String, u32, u32), String> {
+        let mut year: Arg<u32> = Arg::new_optional("-y, --year", "year", "puzzle year", 2022);
+        let mut file: Arg<String> = Arg::new_optional("-f, --file", "file", "input file", String::new());
+        let mut verbosity: Arg<u32> = Arg::new_count("-v, --verbose", "log more");
+        let mut day: Arg<u32> = Arg::new_positional("day", "day to run");
+
+        let mut parser = Parser::new();
+        parser.add_argument(&mut year);
+        parser.add_argument(&mut file);
+        parser.add_argument(&mut verbosity);
+        parser.add_argument(&mut day);
+        let parsed = parser.parse(args(argv));
+        if parsed.is_err() {
+            return Err(error(parsed));
+        }
+
+        match (year, file, verbosity, day) {
+            (Arg::Parsed(year), Arg::Parsed(file), Arg::Parsed(verbosity), Arg::Parsed(day)) => Ok((year, file, verbosity, day)),
+            _ => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn values_that_look_like_flags() {
+        // A flag's value is never taken for a flag itself
+        assert_eq!(parse_run(&["aoc", "1", "-f", "-y"]), Ok((2022, String::from("-y"), 0, 1)));
+        assert_eq!(parse_run(&["aoc", "-f", "-v", "-v", "1"]), Ok((2022, String::from("-v"), 1, 1)));
+        assert_eq!(parse_run(&["aoc", "-vf", "-f", "1"]), Ok((2022, String::from("-f"), 1, 1)));
+        assert_eq!(parse_run(&["aoc", "--file", "--year=2020", "1"]), Ok((2022, String::from("--year=2020"), 0, 1)));
+        assert_eq!(parse_run(&["aoc", "-f", "-h", "1"]), Ok((2022, String::from("-h"), 0, 1)));
+
+        assert_eq!(parse_run(&["aoc", "1", "-f", "-y", "-f", "x"]).unwrap_err().lines().next(), Some("-f given more than once"));
+        assert_eq!(parse_run(&["aoc", "1", "-y", "2020", "-f"]).unwrap_err().lines().next(), Some("missing value for -f <file>"));
+        assert_eq!(parse_run(&["aoc", "1", "--verbose=2"]).unwrap_err().lines().next(), Some("--verbose doesn't take a value"));
+        assert_eq!(parse(&["aoc", "--debug=yes"]).unwrap_err().lines().next(), Some("--debug doesn't take a value"));
+    }
+
+    arguments! {
+        struct BenchArgs for "bench" {
+            runs: usize = new_optional("-n, --runs", "runs", "times to run each phase", 10),
+            debug: bool = new_switch("-d, --debug", "log debugging output"),
+            day: u32 = new_positional("day", "day to benchmark"),
+            part: u32 = new_default_positional("part", "part to benchmark", 0),
+        }
+    }
+
+    #[test]
+    fn typed_arguments() {
+        let parsed = BenchArgs::parse(args(&["aoc", "-dn5", "3"])).ok().unwrap();
+        assert_eq!((parsed.runs, parsed.debug, parsed.day, parsed.part), (5, true, 3, 0));
+
+        let e = error(BenchArgs::parse(args(&["aoc", "-n", "5"])));
+        assert_eq!(e, "bench: missing positional argument <day>\nusage: aoc bench [-n <runs>] [-d] <day> [part]");
+    }
+
+    #[test]
+    fn given_values() {
+        let given = |argv: &[&str]| {
+            let mut text: Arg<Given<String>> = Arg::new_optional("--input-text", "text", "the input itself", Given(None));
+            let mut parser = Parser::new();
+            parser.add_argument(&mut text);
+            parser.parse(args(argv)).ok().unwrap();
+            text.value().ok().unwrap()
+        };
+
+        assert_eq!(given(&["aoc"]), Given(None));
+        assert_eq!(given(&["aoc", "--input-text=1000"]), Given(Some(String::from("1000"))));
+        assert_eq!(given(&["aoc", "--input-text", ""]), Given(Some(String::new())));
+        assert_eq!(given(&["aoc", "--input-text="]), Given(Some(String::new())));
+    }
+
+    #[test]
+    fn parse_numbers() {
+        assert_eq!("3".parse::<Numbers>().unwrap(), Numbers(vec![3]));
+        assert_eq!("1,2,5..9".parse::<Numbers>().unwrap(), Numbers(vec![1, 2, 5, 6, 7, 8, 9]));
+        assert_eq!("4..4".parse::<Numbers>().unwrap().to_string(), "4");
+        assert_eq!("9..5".parse::<Numbers>().unwrap_err().reason, "`9..5` counts down");
+        assert_eq!("1..25".parse::<Numbers>().unwrap().0.len(), 25);
+        assert_eq!("1..4000000000".parse::<Numbers>().unwrap_err().reason, "`1..4000000000` goes past 25");
+        assert!("1..x".parse::<Numbers>().is_err());
+    }
+
+    arguments! {
+        struct RunArgs for "run" {
+            parts: Numbers = new_repeated("-p, --part", "part", "part to solve", Numbers(vec![1, 2])),
+            verbosity: Verbosity = new_count("-v, --verbose", "log more"),
+            days: Numbers = new_list("days", "days to solve"),
+        }
+    }
+
+    #[test]
+    fn lists_and_counts() {
+        let parsed = RunArgs::parse(args(&["aoc", "1", "2", "5..7"])).ok().unwrap();
+        assert_eq!((parsed.parts, parsed.verbosity, parsed.days), (Numbers(vec![1, 2]), Verbosity(LevelFilter::Info), Numbers(vec![1, 2, 5, 6, 7])));
+
+        let parsed = RunArgs::parse(args(&["aoc", "-p", "2", "3", "-vp1", "--verbose"])).ok().unwrap();
+        assert_eq!((parsed.parts, parsed.verbosity, parsed.days), (Numbers(vec![2, 1]), Verbosity(LevelFilter::Trace), Numbers(vec![3])));
+
+        let parsed = RunArgs::parse(args(&["aoc", "-v", "--part=1", "--", "4", "6"])).ok().unwrap();
+        assert_eq!((parsed.parts, parsed.verbosity, parsed.days), (Numbers(vec![1]), Verbosity(LevelFilter::Debug), Numbers(vec![4, 6])));
+
+        let e = error(RunArgs::parse(args(&["aoc", "-p"])));
+        assert_eq!(e, "run: missing value for -p <part>\nusage: aoc run [-p <part>]... [-v]... <days>...");
+        let e = error(RunArgs::parse(args(&["aoc", "-v"])));
+        assert_eq!(e.lines().next(), Some("run: missing positional argument <days>"));
+    }
 }
\ No newline at end of file
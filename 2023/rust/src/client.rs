@@ -0,0 +1,298 @@
+use std::{env, fs, io::{self, Write}, path::{Path, PathBuf}, process::{Command, Stdio}, str::FromStr};
+
+use crate::runner::{self, Source};
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+const USER_AGENT: &str = "advent-of-code-rust input fetcher";
+
+/// Where the puzzle site lives and the session token to use with it.
+///
+/// Settings come from the config file, one `<key> = <value>` per line, and
+/// the `AOC_SESSION` and `AOC_BASE_URL` environment variables override it.
+pub struct Config {
+    pub session: Option<String>,
+    pub base_url: String,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config{session: None, base_url: String::from(DEFAULT_BASE_URL)}
+    }
+}
+
+impl Config {
+    /// Loads the config file, if there is one, then applies the environment.
+    pub fn load() -> Result<Self, String> {
+        let mut config = match config_path() {
+            Some(path) => Config::load_file(&path)?,
+            None => Config::default(),
+        };
+
+        if let Ok(session) = env::var("AOC_SESSION") {
+            config.session = Some(session.trim().to_string());
+        }
+        if let Ok(base_url) = env::var("AOC_BASE_URL") {
+            config.base_url = base_url;
+        }
+
+        Ok(config)
+    }
+
+    /// Loads a config file, treating a missing file as empty.
+    pub fn load_file(path: &Path) -> Result<Self, String> {
+        match fs::read_to_string(path) {
+            Ok(s) => s.parse().map_err(|e: ParseConfigError| format!("{}: {}", path.display(), e.reason)),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Config::default()),
+            Err(e) => Err(format!("{}: {}", path.display(), e)),
+        }
+    }
+}
+
+/// `AOC_CONFIG` if set, otherwise `~/.config/advent-of-code/config`.
+fn config_path() -> Option<PathBuf> {
+    match env::var_os("AOC_CONFIG") {
+        Some(path) => Some(PathBuf::from(path)),
+        None => env::var_os("HOME").map(|home| Path::new(&home).join(".config/advent-of-code/config")),
+    }
+}
+
+pub struct ParseConfigError {
+    pub reason: String,
+}
+
+impl FromStr for Config {
+    type Err = ParseConfigError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut config = Config::default();
+
+        for (i, line) in s.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue
+            }
+
+            let (key, value) = match line.split_once('=') {
+                Some((key, value)) => (key.trim(), value.trim()),
+                None => return Err(ParseConfigError{reason: format!("line {}: expected `<key> = <value>`", i+1)}),
+            };
+
+            match key {
+                "session" => config.session = Some(value.to_string()),
+                "base_url" => config.base_url = value.to_string(),
+                _ => return Err(ParseConfigError{reason: format!("line {}: unknown setting `{}`", i+1, key)}),
+            }
+        }
+
+        Ok(config)
+    }
+}
+
+/// An HTTP client for the puzzle site, authenticated with a session token.
+///
+/// Requests are made with `curl`, which keeps the crate free of a TLS stack,
+/// so it has to be on the `PATH`. The tests that make requests are skipped
+/// without it.
+pub struct Client {
+    base_url: String,
+    session: String,
+}
+
+pub struct Response {
+    pub status: u32,
+    pub body: String,
+}
+
+impl Client {
+    pub fn new(config: &Config) -> Result<Self, String> {
+        match &config.session {
+            Some(session) => Ok(Client{base_url: config.base_url.trim_end_matches('/').to_string(), session: session.clone()}),
+            None => Err(String::from("no session token: set AOC_SESSION or `session` in the config file")),
+        }
+    }
+
+    pub fn url(&self, path: &str) -> String {
+        format!("{}{}", self.base_url, path)
+    }
+
+    pub fn get(&self, path: &str) -> Result<Response, String> {
+        self.request(path, &[])
+    }
+
+    /// Posts a url encoded form.
+    pub fn post(&self, path: &str, form: &[(&str, &str)]) -> Result<Response, String> {
+        let mut args = Vec::new();
+        for (key, value) in form {
+            args.push(String::from("--data-urlencode"));
+            args.push(format!("{}={}", key, value));
+        }
+
+        self.request(path, &args)
+    }
+
+    fn request(&self, path: &str, args: &[String]) -> Result<Response, String> {
+        let url = self.url(path);
+        let mut curl = Command::new("curl")
+            .args(["--silent", "--show-error", "--user-agent", USER_AGENT])
+            // The session is read from stdin so it never shows up in the process list
+            .args(["--config", "-"])
+            // The status code is written after the body so both come back on stdout
+            .args(["--write-out", "\n%{http_code}"])
+            .args(args)
+            .arg(&url)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()
+            .map_err(|e| format!("failed to run curl: {}", e))?;
+
+        let config = format!("cookie = \"session={}\"\n", self.session.replace('\\', "\\\\").replace('"', "\\\""));
+        if let Some(mut stdin) = curl.stdin.take() {
+            stdin.write_all(config.as_bytes()).map_err(|e| format!("failed to pass the session to curl: {}", e))?;
+        }
+        let output = curl.wait_with_output().map_err(|e| format!("failed to run curl: {}", e))?;
+
+        if !output.status.success() {
+            return Err(format!("{}: {}", url, String::from_utf8_lossy(&output.stderr).trim()));
+        }
+
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        match stdout.rsplit_once('\n').map(|(body, status)| (body, status.parse())) {
+            Some((body, Ok(status))) => Ok(Response{status, body: body.to_string()}),
+            _ => Err(format!("{}: no status in response", url)),
+        }
+    }
+}
+
+pub enum Fetched {
+    /// The input was already cached at this path, so nothing was downloaded.
+    Cached(PathBuf),
+    Downloaded(PathBuf),
+}
+
+/// Makes sure a day's input is in the inputs cache, downloading it only if it
+/// isn't there already. Either way the input is then loaded from the cache
+/// with [`runner::read_input`], like any other input file.
+pub fn fetch_input(client: &Client, inputs: &Path, year: u32, day: u32) -> Result<Fetched, String> {
+    if let Ok(path) = runner::find_input(inputs, year, day, &Source::Input) {
+        return Ok(Fetched::Cached(path));
+    }
+
+    let response = client.get(&format!("/{}/day/{}/input", year, day))?;
+    match response.status {
+        200 => (),
+        404 => return Err(format!("{} day {} has no input yet (is the puzzle unlocked?)", year, day)),
+        400 | 500 => return Err(format!("the session token was rejected (HTTP {})", response.status)),
+        status => return Err(format!("{} returned HTTP {}: {}", client.url(""), status, response.body.lines().next().unwrap_or_default())),
+    }
+
+    let path = runner::input_path(inputs, year, day, &Source::Input);
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir).map_err(|e| format!("{}: {}", dir.display(), e))?;
+    }
+    // Cached inputs are never downloaded again, so one cut short mustn't be
+    // left where it would be found. It's written alongside, then moved into place.
+    let partial = path.with_extension("txt.part");
+    fs::write(&partial, response.body).map_err(|e| format!("{}: {}", partial.display(), e))?;
+    fs::rename(&partial, &path).map_err(|e| format!("{}: {}", path.display(), e))?;
+
+    Ok(Fetched::Downloaded(path))
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use std::{io::{Read, Write}, net::TcpListener, process, sync::mpsc, thread};
+
+    use super::*;
+
+    /// Serves a single request with `status` and `body`, sending the request
+    /// it received, including any form it posted, back over the channel.
+    pub(crate) fn stub_server(status: &'static str, body: &'static str) -> (String, mpsc::Receiver<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        let (sender, receiver) = mpsc::channel();
+
+        thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut request = Vec::new();
+            let mut buf = [0; 1024];
+            while !String::from_utf8_lossy(&request).contains("\r\n\r\n") {
+                let n = stream.read(&mut buf).unwrap();
+                request.extend_from_slice(&buf[..n]);
+            }
+
+            let text = String::from_utf8_lossy(&request).to_string();
+            let headers_end = text.find("\r\n\r\n").unwrap() + 4;
+            let length = text[..headers_end].lines()
+                .find_map(|line| line.to_ascii_lowercase().strip_prefix("content-length: ").map(|n| n.parse::<usize>().unwrap()))
+                .unwrap_or(0);
+            while request.len() < headers_end + length {
+                let n = stream.read(&mut buf).unwrap();
+                request.extend_from_slice(&buf[..n]);
+            }
+
+            write!(stream, "HTTP/1.1 {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}", status, body.len(), body).unwrap();
+            sender.send(String::from_utf8_lossy(&request).to_string()).unwrap();
+        });
+
+        (base_url, receiver)
+    }
+
+    pub(crate) fn client(base_url: String) -> Client {
+        Client::new(&Config{session: Some(String::from("abc123")), base_url}).unwrap()
+    }
+
+    /// Fails the test if `curl` can't be run, as requests can't be checked without it.
+    pub(crate) fn require_curl() {
+        assert!(Command::new("curl").arg("--version").output().is_ok(), "curl is needed to test requests, but it isn't installed");
+    }
+
+    #[test]
+    fn parse_config() {
+        let config: Config = "# puzzle site\nsession = abc123\nbase_url = http://localhost:8080\n".parse().ok().unwrap();
+        assert_eq!(config.session.as_deref(), Some("abc123"));
+        assert_eq!(config.base_url, "http://localhost:8080");
+
+        let config: Config = "".parse().ok().unwrap();
+        assert_eq!((config.session, config.base_url.as_str()), (None, DEFAULT_BASE_URL));
+
+        assert!("session abc".parse::<Config>().is_err());
+        assert!("colour = blue".parse::<Config>().is_err());
+    }
+
+    #[test]
+    fn fetch_once_then_cached() {
+        require_curl();
+        let inputs = env::temp_dir().join(format!("aoc-fetch-{}", process::id()));
+        let (base_url, requests) = stub_server("200 OK", "1000\n2000\n");
+        let client = client(base_url);
+
+        let path = match fetch_input(&client, &inputs, 2022, 1) {
+            Ok(Fetched::Downloaded(path)) => path,
+            _ => panic!("input should be downloaded"),
+        };
+        assert_eq!(fs::read_to_string(&path).unwrap(), "1000\n2000\n");
+        assert!(!path.with_extension("txt.part").exists());
+
+        let request = requests.recv().unwrap();
+        assert!(request.starts_with("GET /2022/day/1/input "), "{}", request);
+        assert!(request.contains("Cookie: session=abc123"), "{}", request);
+
+        // The stub only answers once, so this must come from the cache
+        assert!(matches!(fetch_input(&client, &inputs, 2022, 1), Ok(Fetched::Cached(p)) if p == path));
+
+        fs::remove_dir_all(inputs).unwrap();
+    }
+
+    #[test]
+    fn fetch_reports_locked_puzzle() {
+        require_curl();
+        let inputs = env::temp_dir().join(format!("aoc-fetch-locked-{}", process::id()));
+        let (base_url, _requests) = stub_server("404 Not Found", "Please don't repeatedly request this endpoint before it unlocks!");
+
+        let e = fetch_input(&client(base_url), &inputs, 2022, 25).err().unwrap();
+        assert!(e.contains("no input yet"), "{}", e);
+        assert!(!inputs.exists());
+    }
+}
\ No newline at end of file
@@ -1,15 +0,0 @@
-pub mod one;
-pub mod two;
-pub mod three;
-pub mod four;
-pub mod five;
-pub mod six;
-pub mod seven;
-pub mod eight;
-pub mod nine;
-pub mod ten;
-pub mod eleven;
-pub mod twelve;
-pub mod thirteen;
-pub mod fourteen;
-pub mod fifteen;
\ No newline at end of file
@@ -0,0 +1,178 @@
+use std::{fmt::Display, str::FromStr, time::Duration};
+
+use crate::registry::NotImplemented;
+
+/// Malformed puzzle input.
+///
+/// Days create these from the slice of input they couldn't make sense of.
+/// The line and column of that slice are filled in by [`InputError::locate`]
+/// once the error reaches code holding the whole input, and the year and day
+/// as it passes back through the registry.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct InputError {
+    pub year: Option<u32>,
+    pub day: Option<u32>,
+    pub line: Option<usize>,
+    pub column: Option<usize>,
+    pub text: String,
+    pub reason: String,
+    /// Address of the offending slice, used to find it within the input.
+    origin: usize,
+}
+
+impl InputError {
+    pub fn new(reason: impl Display, text: &str) -> Self {
+        InputError{
+            year: None,
+            day: None,
+            line: None,
+            column: None,
+            text: text.to_string(),
+            reason: reason.to_string(),
+            origin: text.as_ptr() as usize,
+        }
+    }
+
+    /// An error about the input as a whole, such as having no solution, that
+    /// can't be pinned to any particular text.
+    pub fn unlocated(reason: impl Display) -> Self {
+        InputError{
+            year: None,
+            day: None,
+            line: None,
+            column: None,
+            text: String::new(),
+            reason: reason.to_string(),
+            origin: 0,
+        }
+    }
+
+    /// Works out the line and column of the offending text, provided it was
+    /// sliced from `input`.
+    pub fn locate(mut self, input: &str) -> Self {
+        let offset = match self.origin.checked_sub(input.as_ptr() as usize) {
+            // The whole slice has to lie within the input, not merely start at its end
+            Some(offset) if offset + self.text.len() <= input.len() && input.is_char_boundary(offset) => offset,
+            _ => return self,
+        };
+
+        let before = &input[..offset];
+        self.line = Some(before.matches('\n').count() + 1);
+        self.column = Some(before.rsplit('\n').next().unwrap_or_default().chars().count() + 1);
+        self
+    }
+
+    pub fn on_day(mut self, day: u32) -> Self {
+        self.day = Some(day);
+        self
+    }
+
+    pub fn in_year(mut self, year: u32) -> Self {
+        self.year = Some(year);
+        self
+    }
+}
+
+impl Display for InputError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match (self.year, self.day) {
+            (Some(year), Some(day)) => write!(f, "{} day {}, ", year, day)?,
+            (None, Some(day)) => write!(f, "day {}, ", day)?,
+            _ => (),
+        }
+        match (self.line, self.column) {
+            (Some(line), Some(column)) => write!(f, "line {}, column {}: ", line, column)?,
+            _ => write!(f, "input: ")?,
+        }
+        match self.text.as_str() {
+            "" => write!(f, "{}", self.reason),
+            text => write!(f, "{}: `{}`", self.reason, text),
+        }
+    }
+}
+
+/// Parses `text`, reporting failures as an [`InputError`] pointing at it.
+pub fn parse<T: FromStr>(text: &str) -> Result<T, InputError> where <T as FromStr>::Err: Display {
+    text.parse().map_err(|e| InputError::new(e, text))
+}
+
+#[derive(Debug)]
+pub enum Error {
+    Input(InputError),
+    NotImplemented(NotImplemented),
+    /// A solver panicked, with where and why.
+    Panicked(String),
+    /// A solver ran past its time limit.
+    TimedOut(Duration),
+}
+
+impl Error {
+    /// Attributes an input error to the year it was raised in.
+    pub fn in_year(self, year: u32) -> Self {
+        match self {
+            Error::Input(e) => Error::Input(e.in_year(year)),
+            e => e,
+        }
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::Input(e) => write!(f, "{}", e),
+            Error::NotImplemented(e) => write!(f, "{}", e),
+            Error::Panicked(reason) => write!(f, "panicked {}", reason),
+            Error::TimedOut(limit) => write!(f, "TIMEOUT after {:.2?}", limit),
+        }
+    }
+}
+
+impl From<NotImplemented> for Error {
+    fn from(value: NotImplemented) -> Self {
+        Error::NotImplemented(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn locate_in_input() {
+        let input = "first line\nsecond bad line\n";
+        let bad = &input[18..21];
+
+        let e = InputError::new("unexpected word", bad).locate(input);
+        assert_eq!((e.line, e.column), (Some(2), Some(8)));
+        assert_eq!(e.to_string(), "line 2, column 8: unexpected word: `bad`");
+    }
+
+    #[test]
+    fn locate_outside_input() {
+        // Both halves are sliced from one allocation, so where the second
+        // starts is known to be right after the end of the first
+        let text = String::from("some other inputbad");
+        let (input, bad) = text.split_at(16);
+
+        let e = InputError::new("unexpected word", bad).locate(input);
+        assert_eq!((e.line, e.column), (None, None));
+        let e = InputError::new("unexpected word", &text[10..19]).locate(input);
+        assert_eq!((e.line, e.column), (None, None));
+
+        let e = InputError::new("unexpected word", &text[11..16]).locate(input);
+        assert_eq!((e.line, e.column), (Some(1), Some(12)));
+    }
+
+    #[test]
+    fn parse_reports_text() {
+        let input = "1\nx2\n";
+        let e = parse::<u32>(&input[2..4]).unwrap_err().locate(input).on_day(1).in_year(2022);
+        assert_eq!(e.to_string(), "2022 day 1, line 2, column 1: invalid digit found in string: `x2`");
+    }
+
+    #[test]
+    fn unlocated_reason_only() {
+        let e = InputError::unlocated("no path to the end").locate("abc").on_day(12);
+        assert_eq!(e.to_string(), "day 12, input: no path to the end");
+    }
+}
\ No newline at end of file
@@ -1,2 +1,14 @@
+pub mod alloc;
+pub mod answers;
+pub mod bench;
 pub mod cli;
-pub mod days;
\ No newline at end of file
+pub mod client;
+pub mod error;
+pub mod registry;
+pub mod repl;
+pub mod report;
+pub mod runner;
+pub mod scaffold;
+pub mod solver;
+pub mod submit;
+pub mod years;
\ No newline at end of file
@@ -1,77 +1,745 @@
 use log::{LevelFilter, error, debug};
-use std::env;
+use std::{env, io::{self, BufRead, Write}, num::NonZeroUsize, path::Path, process, thread, time::Duration};
 
 use env_logger::Builder;
 
-use advent_of_code_rust::cli;
-use advent_of_code_rust::days::*;
+use advent_of_code_rust::alloc::{self, Allocs};
+use advent_of_code_rust::answers::{Answers, Status};
+use advent_of_code_rust::bench::{self, Stats};
+use advent_of_code_rust::cli::{self, Given, Numbers, Verbosity};
+use advent_of_code_rust::client::{self, Client, Config, Fetched};
+use advent_of_code_rust::error::Error;
+use advent_of_code_rust::registry::PARTS;
+use advent_of_code_rust::report::{self, Format};
+use advent_of_code_rust::repl::{self, Command, Session};
+use advent_of_code_rust::runner::{self, DayError, DayResult, Source};
+use advent_of_code_rust::scaffold;
+use advent_of_code_rust::submit::{self, Guesses, Precheck, Verdict};
+use advent_of_code_rust::years::CALENDAR;
 
 fn main() {
-    let mut day: cli::Arg<u32> = cli::Arg::new_positional();
-    let mut part: cli::Arg<u32> = cli::Arg::new_positional();
-    let mut file_contents: cli::Arg<cli::FileContents> = cli::Arg::new_positional();
-    let mut debug: cli::Arg::<bool> = cli::Arg::new_optional(String::from("d"), false);
-
-    let mut parser = cli::Parser::new();
-    parser.add_argument(&mut day);
-    parser.add_argument(&mut part);
-    parser.add_argument(&mut file_contents);
-    parser.add_argument(&mut debug);
-    if let Err(e) = parser.parse(env::args().collect()) {
-        println!("{}", e.reason);
+    let mut commands = cli::Subcommands::new();
+    commands.add("run", "solve parts of one or more days (the default)", run);
+    commands.add("list", "list the registered days", list);
+    commands.add("run-all", "solve every day and show a table of answers and times", run_all);
+    commands.add("check", "check every answer against answers.txt, or --record them", check);
+    commands.add("bench", "time a day's parse and parts over repeated runs", bench);
+    commands.add("fetch", "download a day's input", fetch);
+    commands.add("submit", "submit a part's answer", submit);
+    commands.add("new", "create a new day from a template", new);
+    commands.add("repl", "explore a day's parsed input interactively", repl);
+    commands.set_default("run");
+
+    if let Err(stopped) = commands.dispatch(env::args().collect()) {
+        stop(stopped);
+    }
+}
+
+/// Shows asked for help on stdout, or a command line error on stderr with
+/// exit status 2.
+fn stop(stopped: cli::Stopped) -> ! {
+    match stopped {
+        cli::Stopped::Help(help) => {
+            println!("{}", help);
+            process::exit(0);
+        }
+        cli::Stopped::Error(e) => {
+            eprintln!("{}", e.reason);
+            process::exit(2);
+        }
+    }
+}
+
+fn init_logger(level: LevelFilter) {
+    Builder::new().filter_level(level).init();
+
+    debug!("Logging at {}", level);
+}
+
+/// Logs at debug level for `-d`, or more if `-v` asks for it.
+fn log_level(debug: bool, verbosity: Verbosity) -> LevelFilter {
+    match debug {
+        true => verbosity.0.max(LevelFilter::Debug),
+        false => verbosity.0,
+    }
+}
+
+cli::arguments! {
+    struct RunArgs for "run" {
+        year: u32 = new_optional("-y, --year", "year", "puzzle year", CALENDAR.default_year()),
+        parts: Numbers = new_repeated("-p, --part", "part", "part to solve, given once for each", Numbers(PARTS.to_vec())),
+        // Without a file the input is looked up under the inputs or examples directory. `-` is stdin.
+        file: String = new_optional("-f, --file", "file", "input file, or - for stdin", String::new()),
+        inputs: String = new_optional("-i, --inputs", "dir", "where puzzle inputs are kept", String::from("inputs")),
+        example: u32 = new_optional("--example", "n", "solve an example input instead, 0 for the real input", 0),
+        text: Given<String> = new_optional("--input-text", "text", "the input itself, instead of a file", Given(None)),
+        output: Format = new_optional("--output", "format", "text, json or csv", Format::Text),
+        timeout: f64 = new_optional("--timeout", "seconds", "time limit for each part, 0 for none", 0.0),
+        debug: bool = new_switch("-d, --debug", "log debugging output"),
+        verbosity: Verbosity = new_count("-v, --verbose", "log more, -vv for everything"),
+        watch: bool = new_switch("--watch", "re-run whenever the input file changes"),
+        days: Numbers = new_list("days", "days to solve, such as 1 2 5..9"),
+    }
+}
+
+fn run(args: Vec<String>) {
+    let RunArgs{year, parts, file, inputs, example, text, output, timeout, debug, verbosity, watch, days} = match RunArgs::parse(args) {
+        Ok(args) => args,
+        Err(stopped) => stop(stopped),
+    };
+
+    init_logger(log_level(debug, verbosity));
+
+    let (days, parts, limit) = (days.0, parts.0, time_limit(timeout));
+    if days.len() > 1 && (!file.is_empty() || text.0.is_some() || watch) {
+        error!("--file, --input-text and --watch need a single day");
+        process::exit(1);
+    }
+
+    if watch {
+        let path = match (&text.0, runner::choose_input(year, days[0], &file, &inputs, example)) {
+            (None, Ok(path)) if path == Path::new(runner::STDIN) => Err(String::from("stdin can't be watched, give an input file")),
+            (None, path) => path,
+            (Some(_), _) => Err(String::from("--input-text can't be watched, give an input file")),
+        };
+        let path = match path {
+            Ok(path) => path,
+            Err(e) => {
+                error!("{}", e);
+                process::exit(1);
+            }
+        };
+        watch_day(year, days[0], &parts, &path, output, limit);
+    }
+
+    // A single part is answered on its own, so it can be used in scripts
+    if let ([day], [part], Format::Text) = (days.as_slice(), parts.as_slice(), output) {
+        let input = match runner::load_input(year, *day, &file, &inputs, example, text.0.as_deref()) {
+            Ok(input) => input,
+            Err(e) => {
+                error!("{}", e);
+                process::exit(1);
+            }
+        };
+
+        let answer = CALENDAR.day(year, *day).map_err(Error::from)
+            .and_then(|registered| runner::run_day(year, registered, &input, &[*part], limit))
+            .and_then(|run| run.parts.into_iter().next().expect("the part was run").answer);
+        match answer {
+            Ok(answer) => println!("{}", answer),
+            Err(e) => {
+                error!("{}", e);
+                process::exit(1);
+            }
+        }
         return
     }
 
-    let mut logger = Builder::new();
-    logger.filter_level(LevelFilter::Info);
-
-    if let cli::Arg::Parsed(debug) = debug {
-        if debug {
-            logger.filter_level(LevelFilter::Debug);
-        }
-        logger.init();
-
-        debug!("Debugging enabled: {}", debug);
-    }
-
-    if let cli::Arg::Parsed(file) = file_contents {
-        if let cli::Arg::Parsed(day) = day {
-            if let cli::Arg::Parsed(part) = part {
-                match (day, part) {
-                    (1, 1) => one::solve_part_1(&file.contents),
-                    (1, 2) => one::solve_part_2(&file.contents),
-                    (2, 1) => two::solve_part_1(&file.contents),
-                    (2, 2) => two::solve_part_2(&file.contents),
-                    (3, 1) => three::solve_part_1(&file.contents),
-                    (3, 2) => three::solve_part_2(&file.contents),
-                    (4, 1) => four::solve_part_1(&file.contents),
-                    (4, 2) => four::solve_part_2(&file.contents),
-                    (5, 1) => five::solve_part_1(&file.contents),
-                    (5, 2) => five::solve_part_2(&file.contents),
-                    (6, 1) => six::solve_part_1(&file.contents),
-                    (6, 2) => six::solve_part_2(&file.contents),
-                    (7, 1) => seven::solve_part_1(&file.contents),
-                    (7, 2) => seven::solve_part_2(&file.contents),
-                    (8, 1) => eight::solve_part_1(&file.contents),
-                    (8, 2) => eight::solve_part_2(&file.contents),
-                    (9, 1) => nine::solve_part_1(&file.contents),
-                    (9, 2) => nine::solve_part_2(&file.contents),
-                    (10, 1) => ten::solve_part_1(&file.contents),
-                    (10, 2) => ten::solve_part_2(&file.contents),
-                    (11, 1) => eleven::solve_part_1(&file.contents),
-                    (11, 2) => eleven::solve_part_2(&file.contents),
-                    (12, 1) => twelve::solve_part_1(&file.contents),
-                    (12, 2) => twelve::solve_part_2(&file.contents),
-                    (13, 1) => thirteen::solve_part_1(&file.contents),
-                    (13, 2) => thirteen::solve_part_2(&file.contents),
-                    (14, 1) => fourteen::solve_part_1(&file.contents),
-                    (14, 2) => fourteen::solve_part_2(&file.contents),
-                    (15, 1) => fifteen::solve_part_1(&file.contents),
-                    (15, 2) => fifteen::solve_part_2(&file.contents),
-                    _ => error!("day {} part {} not implemented", day, part),
+    let results: Vec<DayResult> = days.iter().map(|&day| {
+        let run = CALENDAR.day(year, day).map_err(|e| DayError::Invalid(e.into())).and_then(|registered| {
+            let input = runner::load_input(year, day, &file, &inputs, example, text.0.as_deref()).map_err(DayError::Missing)?;
+            runner::run_day(year, registered, &input, &parts, limit).map_err(DayError::Invalid)
+        });
+        DayResult{year, day, run}
+    }).collect();
+    print_results(output, &results);
+
+    // Days were asked for by number, so a missing input is a failure here too
+    if results.iter().any(|result| result.failed() || matches!(result.run, Err(DayError::Missing(_)))) {
+        process::exit(1);
+    }
+}
+
+/// Re-runs parts every time their input file changes, until interrupted.
+fn watch_day(year: u32, day: u32, parts: &[u32], path: &Path, output: Format, limit: Option<Duration>) -> ! {
+    let registered = match CALENDAR.day(year, day) {
+        Ok(registered) => registered,
+        Err(e) => {
+            error!("{}", e);
+            process::exit(1);
+        }
+    };
+
+    let mut watcher = runner::Watcher::new(path);
+    loop {
+        if watcher.changed() {
+            // Clear the screen and move the cursor back to the top
+            print!("\x1b[2J\x1b[H");
+            println!("watching {} (ctrl-c to stop)", path.display());
+            match runner::read_input(path) {
+                Ok(input) => {
+                    let run = runner::run_day(year, registered, &input, parts, limit).map_err(DayError::Invalid);
+                    print_results(output, &[DayResult{year, day, run}]);
                 }
+                Err(e) => println!("{}", e),
             }
         }
+
+        thread::sleep(Duration::from_millis(250));
+    }
+}
+
+fn list(args: Vec<String>) {
+    if let Err(stopped) = cli::Parser::for_subcommand("list").parse(args) {
+        stop(stopped);
+    }
+
+    for year in CALENDAR.years() {
+        println!("{}", year.year);
+        for day in year.registry.days() {
+            println!("  {:>2} {:<10} parts {:?}", day.day, day.name, PARTS);
+        }
+    }
+}
+
+cli::arguments! {
+    struct RunAllArgs for "run-all" {
+        inputs: String = new_optional("-i, --inputs", "dir", "where puzzle inputs are kept", String::from("inputs")),
+        output: Format = new_optional("--output", "format", "text, json or csv", Format::Text),
+        threads: usize = new_optional("-j, --threads", "threads", "days to run at once", runner::default_threads()),
+        timeout: f64 = new_optional("--timeout", "seconds", "time limit for each part, 0 for none", 0.0),
+        debug: bool = new_switch("-d, --debug", "log debugging output"),
+        verbosity: Verbosity = new_count("-v, --verbose", "log more, -vv for everything"),
+    }
+}
+
+fn run_all(args: Vec<String>) {
+    let RunAllArgs{inputs, output, threads, timeout, debug, verbosity} = match RunAllArgs::parse(args) {
+        Ok(args) => args,
+        Err(stopped) => stop(stopped),
+    };
+
+    init_logger(log_level(debug, verbosity));
+
+    let results = runner::run_all(&CALENDAR, Path::new(&inputs), threads, time_limit(timeout));
+    print_results(output, &results);
+    if results.iter().any(DayResult::failed) {
+        process::exit(1);
     }
+}
+
+fn time_limit(seconds: f64) -> Option<Duration> {
+    match seconds > 0.0 {
+        true => Some(Duration::from_secs_f64(seconds)),
+        false => None,
+    }
+}
+
+fn print_results(format: Format, results: &[DayResult]) {
+    match format {
+        Format::Text => print_table(results),
+        Format::Json => println!("{}", report::json(results)),
+        Format::Csv => print!("{}", report::csv(results)),
+    }
+}
+
+fn print_table(results: &[DayResult]) {
+    println!("{:<4}  {:>3}  {:>5}  {:<40}  {:>10}  {:>10}{}", "year", "day", "part", "answer", "parse", "solve", match alloc::ENABLED {
+        true => format!("  {:>8}  {:>10}  {:>10}", "allocs", "bytes", "peak"),
+        false => String::new(),
+    });
+
+    let (mut total_parse, mut total_solve) = (Duration::ZERO, Duration::ZERO);
+    for result in results {
+        match &result.run {
+            Ok(run) => {
+                total_parse += run.parse;
+                // Parsing is shared by every part, so its time is only shown once. With
+                // allocations counted it gets its own row so they can be shown for it too.
+                if alloc::ENABLED {
+                    println!("{:<4}  {:>3}  {:>5}  {:<40}  {:>10}  {:>10}{}", result.year, result.day, "parse", "", format!("{:.2?}", run.parse), "", alloc_columns(run.parse_allocs));
+                }
+                for (i, part) in run.parts.iter().enumerate() {
+                    let answer = match &part.answer {
+                        Ok(answer) => answer.to_string(),
+                        Err(e @ Error::TimedOut(_)) => e.to_string(),
+                        Err(e) => format!("error: {}", e),
+                    };
+                    let mut lines = answer.lines();
+                    let first = lines.next().unwrap_or_default();
+                    let parse = match (i, alloc::ENABLED) {
+                        (0, false) => format!("{:.2?}", run.parse),
+                        _ => String::new(),
+                    };
+                    println!("{:<4}  {:>3}  {:>5}  {:<40}  {:>10}  {:>10}{}", result.year, result.day, part.part, first, parse, format!("{:.2?}", part.time), alloc_columns(part.allocs));
+                    // Multi-line answers continue on their own rows under the answer column
+                    for line in lines {
+                        println!("{:<4}  {:>3}  {:>5}  {:<40}", "", "", "", line);
+                    }
+                    total_solve += part.time;
+                }
+            }
+            Err(DayError::Invalid(e)) => println!("{:<4}  {:>3}  {:>5}  error: {}", result.year, result.day, "-", e),
+            Err(DayError::Missing(e)) => println!("{:<4}  {:>3}  {:>5}  {}", result.year, result.day, "-", e),
+        }
+    }
+
+    println!("{:<4}  {:>3}  {:>5}  {:<40}  {:>10}  {:>10}", "", "", "", "total", format!("{:.2?}", total_parse), format!("{:.2?}", total_solve));
+}
+
+/// The allocation columns of a table row, empty when allocations aren't counted.
+fn alloc_columns(allocs: Option<Allocs>) -> String {
+    match allocs {
+        Some(allocs) => format!("  {:>8}  {:>10}  {:>10}", allocs.count, alloc::bytes(allocs.bytes), alloc::bytes(allocs.peak)),
+        None => String::new(),
+    }
+}
+
+cli::arguments! {
+    struct CheckArgs for "check" {
+        inputs: String = new_optional("-i, --inputs", "dir", "where puzzle inputs and answers.txt are kept", String::from("inputs")),
+        threads: usize = new_optional("-j, --threads", "threads", "days to run at once", runner::default_threads()),
+        timeout: f64 = new_optional("--timeout", "seconds", "time limit for each part, 0 for none", 0.0),
+        debug: bool = new_switch("-d, --debug", "log debugging output"),
+        verbosity: Verbosity = new_count("-v, --verbose", "log more, -vv for everything"),
+        record: bool = new_switch("--record", "record answers instead of checking them"),
+    }
+}
+
+fn check(args: Vec<String>) {
+    let CheckArgs{inputs, threads, timeout, debug, verbosity, record} = match CheckArgs::parse(args) {
+        Ok(args) => args,
+        Err(stopped) => stop(stopped),
+    };
+
+    init_logger(log_level(debug, verbosity));
+
+    let path = Path::new(&inputs).join("answers.txt");
+    let mut answers = match Answers::load(&path) {
+        Ok(answers) => answers,
+        Err(e) => {
+            error!("{}", e);
+            process::exit(1);
+        }
+    };
+
+    let results = runner::run_all(&CALENDAR, Path::new(&inputs), threads, time_limit(timeout));
+
+    if record {
+        let mut count = 0;
+        for result in &results {
+            if let Ok(run) = &result.run {
+                // Parts that failed to solve keep whatever was recorded before
+                for part in &run.parts {
+                    if let Ok(answer) = &part.answer {
+                        answers.record(result.year, result.day, part.part, answer);
+                        count += 1;
+                    }
+                }
+            }
+        }
+
+        if let Err(e) = answers.save(&path) {
+            error!("{}", e);
+            process::exit(1);
+        }
+        println!("recorded {} answers to {}", count, path.display());
+        return
+    }
+
+    let (mut passed, mut failed, mut missing) = (0, 0, 0);
+    for result in &results {
+        match &result.run {
+            Ok(run) => for part in &run.parts {
+                let answer = match &part.answer {
+                    Ok(answer) => answer,
+                    Err(e) => {
+                        failed += 1;
+                        let status = match e {
+                            Error::TimedOut(_) => "timeout",
+                            _ => "error",
+                        };
+                        println!("{:<4}  {:>3}  {:>4}  {:<7}  {}", result.year, result.day, part.part, status, e);
+                        continue
+                    }
+                };
+
+                let (status, detail) = match answers.check(result.year, result.day, part.part, answer) {
+                    Status::Pass => {
+                        passed += 1;
+                        ("pass", String::new())
+                    }
+                    Status::Fail(expected) => {
+                        failed += 1;
+                        ("FAIL", format!(" (expected {})", expected.replace('\n', " / ")))
+                    }
+                    Status::Missing => {
+                        missing += 1;
+                        ("missing", String::new())
+                    }
+                };
+                println!("{:<4}  {:>3}  {:>4}  {:<7}  {}{}", result.year, result.day, part.part, status, answer.to_string().replace('\n', " / "), detail);
+            }
+            Err(DayError::Invalid(e)) => {
+                failed += 1;
+                println!("{:<4}  {:>3}  {:>4}  {:<7}  {}", result.year, result.day, "-", "error", e);
+            }
+            Err(DayError::Missing(e)) => println!("{:<4}  {:>3}  {:>4}  {:<7}  {}", result.year, result.day, "-", "skipped", e),
+        }
+    }
+
+    println!("{} passed, {} failed, {} missing", passed, failed, missing);
+    if failed > 0 {
+        process::exit(1);
+    }
+}
+
+cli::arguments! {
+    struct BenchArgs for "bench" {
+        year: u32 = new_optional("-y, --year", "year", "puzzle year", CALENDAR.default_year()),
+        day: u32 = new_positional("day", "day to benchmark"),
+        part: u32 = new_default_positional("part", "part to benchmark, 0 for every part", 0),
+        runs: NonZeroUsize = new_optional("-n, --runs", "runs", "times to run each phase", NonZeroUsize::new(10).unwrap()),
+        inputs: String = new_optional("-i, --inputs", "dir", "where puzzle inputs are kept", String::from("inputs")),
+    }
+}
+
+fn bench(args: Vec<String>) {
+    let BenchArgs{year, day, part, runs, inputs} = match BenchArgs::parse(args) {
+        Ok(args) => args,
+        Err(stopped) => stop(stopped),
+    };
 
+    init_logger(LevelFilter::Info);
+
+    let registered = match CALENDAR.day(year, day) {
+        Ok(d) => d,
+        Err(e) => {
+            error!("{}", e);
+            process::exit(1);
+        }
+    };
+
+    let input = runner::find_input(Path::new(&inputs), year, day, &Source::Input)
+        .map_err(|e| e.to_string())
+        .and_then(|path| runner::read_input(&path));
+    let input = match input {
+        Ok(input) => input,
+        Err(e) => {
+            error!("{}", e);
+            process::exit(1);
+        }
+    };
+
+    let parts = match part {
+        0 => PARTS.to_vec(),
+        part => vec![part],
+    };
+
+    println!("{} day {} ({} runs)", year, day, runs);
+    print!("{:<8}  {:>10}  {:>10}  {:>10}  {:>10}", "", "min", "median", "mean", "max");
+    match alloc::ENABLED {
+        true => println!("  {:>8}  {:>10}  {:>10}", "allocs", "bytes", "peak"),
+        false => println!(),
+    }
+    let parsed = match bench::bench_parse(registered, &input, runs) {
+        Ok(result) => {
+            print_stats("parse", &result.parse, result.allocs);
+            result.parsed
+        }
+        Err(e) => {
+            error!("{}", e.in_year(year));
+            process::exit(1);
+        }
+    };
+    let mut failed = false;
+    for part in parts {
+        match bench::bench_part(registered, part, parsed.as_ref(), runs) {
+            Ok(result) => print_stats(&format!("part {}", result.part), &result.solve, result.allocs),
+            Err(e) => {
+                error!("{}", e.in_year(year));
+                failed = true;
+            }
+        }
+    }
+    if failed {
+        process::exit(1);
+    }
+}
+
+fn print_stats(label: &str, stats: &Stats, allocs: Option<Allocs>) {
+    println!(
+        "{:<8}  {:>10}  {:>10}  {:>10}  {:>10}{}",
+        label,
+        format!("{:.2?}", stats.min),
+        format!("{:.2?}", stats.median),
+        format!("{:.2?}", stats.mean),
+        format!("{:.2?}", stats.max),
+        alloc_columns(allocs),
+    );
+}
+
+cli::arguments! {
+    struct FetchArgs for "fetch" {
+        year: u32 = new_positional("year", "puzzle year"),
+        day: u32 = new_positional("day", "day to download the input for"),
+        inputs: String = new_optional("-i, --inputs", "dir", "where puzzle inputs are kept", String::from("inputs")),
+    }
+}
+
+fn fetch(args: Vec<String>) {
+    let FetchArgs{year, day, inputs} = match FetchArgs::parse(args) {
+        Ok(args) => args,
+        Err(stopped) => stop(stopped),
+    };
+
+    init_logger(LevelFilter::Info);
+
+    let fetched = Config::load()
+        .and_then(|config| Client::new(&config))
+        .and_then(|client| client::fetch_input(&client, Path::new(&inputs), year, day));
+
+    let path = match fetched {
+        Ok(Fetched::Cached(path)) => {
+            println!("{} day {} is already cached", year, day);
+            path
+        }
+        Ok(Fetched::Downloaded(path)) => {
+            println!("downloaded {} day {}", year, day);
+            path
+        }
+        Err(e) => {
+            error!("{}", e);
+            process::exit(1);
+        }
+    };
+
+    match runner::read_input(&path) {
+        Ok(input) => println!("{}: {} lines", path.display(), input.lines().count()),
+        Err(e) => {
+            error!("{}", e);
+            process::exit(1);
+        }
+    }
+}
+
+cli::arguments! {
+    struct SubmitArgs for "submit" {
+        year: u32 = new_optional("-y, --year", "year", "puzzle year", CALENDAR.default_year()),
+        day: u32 = new_positional("day", "day to submit"),
+        part: u32 = new_positional("part", "part to submit"),
+        inputs: String = new_optional("-i, --inputs", "dir", "where puzzle inputs and guesses.txt are kept", String::from("inputs")),
+        force: bool = new_switch("--force", "submit answers outside the known bounds"),
+    }
+}
+
+fn submit(args: Vec<String>) {
+    let SubmitArgs{year, day, part, inputs, force} = match SubmitArgs::parse(args) {
+        Ok(args) => args,
+        Err(stopped) => stop(stopped),
+    };
+
+    init_logger(LevelFilter::Info);
+
+    let input = runner::find_input(Path::new(&inputs), year, day, &Source::Input)
+        .map_err(|e| e.to_string())
+        .and_then(|path| runner::read_input(&path));
+    let answer = input.and_then(|input| CALENDAR.solve(year, day, part, &input).map_err(|e| e.to_string()));
+    let answer = match answer {
+        Ok(answer) => answer,
+        Err(e) => {
+            error!("{}", e);
+            process::exit(1);
+        }
+    };
+
+    let path = Path::new(&inputs).join("guesses.txt");
+    let mut guesses = match Guesses::load(&path) {
+        Ok(guesses) => guesses,
+        Err(e) => {
+            error!("{}", e);
+            process::exit(1);
+        }
+    };
+
+    let now = submit::now();
+    match guesses.precheck(year, day, part, &answer, now) {
+        Precheck::Ready => (),
+        Precheck::Throttled(wait) => {
+            println!("answered too recently, wait {}s before submitting {}", wait, answer);
+            process::exit(1);
+        }
+        Precheck::AlreadyCorrect(correct) => {
+            println!("{} day {} part {} was already solved with {}", year, day, part, correct);
+            return
+        }
+        Precheck::KnownWrong(verdict) => {
+            println!("{} was already submitted and was {}", answer, verdict);
+            process::exit(1);
+        }
+        Precheck::OutOfBounds{low, high} => {
+            let bound = |b: Option<i64>| b.map(|b| b.to_string()).unwrap_or(String::from("?"));
+            println!("warning: {} is outside the known bounds ({} < answer < {})", answer, bound(low), bound(high));
+            if !force {
+                println!("not submitted, pass --force to submit it anyway");
+                process::exit(1);
+            }
+        }
+    }
+
+    let outcome = Config::load()
+        .and_then(|config| Client::new(&config))
+        .and_then(|client| submit::submit(&client, year, day, part, &answer));
+    let outcome = match outcome {
+        Ok(outcome) => outcome,
+        Err(e) => {
+            error!("{}", e);
+            process::exit(1);
+        }
+    };
+
+    guesses.record(year, day, part, &answer, &outcome, now);
+    if let Err(e) = guesses.save(&path) {
+        error!("{}", e);
+    }
+
+    match outcome.wait {
+        0 => println!("{}: {}", answer, outcome.verdict),
+        wait => println!("{}: {} (wait {}s before the next guess)", answer, outcome.verdict, wait),
+    }
+
+    if outcome.verdict == Verdict::Correct {
+        // A correct answer is also a known answer for `check`
+        let path = Path::new(&inputs).join("answers.txt");
+        let saved = Answers::load(&path).and_then(|mut answers| {
+            answers.record(year, day, part, &answer);
+            answers.save(&path)
+        });
+        if let Err(e) = saved {
+            error!("{}", e);
+        }
+    } else if outcome.verdict != Verdict::Solved {
+        process::exit(1);
+    }
+}
+
+cli::arguments! {
+    struct NewArgs for "new" {
+        year: u32 = new_positional("year", "puzzle year"),
+        day: u32 = new_positional("day", "day to create"),
+        inputs: String = new_optional("-i, --inputs", "dir", "where to create the empty input", String::from("inputs")),
+    }
+}
+
+fn new(args: Vec<String>) {
+    let NewArgs{year, day, inputs} = match NewArgs::parse(args) {
+        Ok(args) => args,
+        Err(stopped) => stop(stopped),
+    };
+
+    init_logger(LevelFilter::Info);
+
+    // Days are generated into this crate's own source tree, wherever it's run from
+    let src = Path::new(env!("CARGO_MANIFEST_DIR")).join("src");
+    match scaffold::new_day(&src, Path::new(&inputs), year, day) {
+        Ok(written) => {
+            for path in written {
+                println!("wrote {}", path.display());
+            }
+            println!("rebuild to add {} day {}", year, day);
+        }
+        Err(e) => {
+            error!("{}", e);
+            process::exit(1);
+        }
+    }
+}
+
+cli::arguments! {
+    struct ReplArgs for "repl" {
+        year: u32 = new_optional("-y, --year", "year", "puzzle year", CALENDAR.default_year()),
+        day: u32 = new_positional("day", "day to explore"),
+        file: String = new_default_positional("file", "input file", String::new()),
+        inputs: String = new_optional("-i, --inputs", "dir", "where puzzle inputs are kept", String::from("inputs")),
+        example: u32 = new_optional("--example", "n", "explore an example input instead, 0 for the real input", 0),
+    }
+}
+
+fn repl(args: Vec<String>) {
+    let ReplArgs{year, day, file, inputs, example} = match ReplArgs::parse(args) {
+        Ok(args) => args,
+        Err(stopped) => stop(stopped),
+    };
+
+    // Everything is logged, and `debug` moves the level the log crate lets through
+    Builder::new().filter_level(LevelFilter::Debug).init();
+    log::set_max_level(LevelFilter::Info);
+
+    // Commands are read from stdin, so the input can't be
+    if file == runner::STDIN {
+        error!("the repl reads commands from stdin, so its input has to come from a file");
+        process::exit(1);
+    }
+
+    let session = CALENDAR.day(year, day)
+        .map_err(|e| e.to_string())
+        .and_then(|registered| runner::choose_input(year, day, &file, &inputs, example).map(|path| (registered, path)))
+        .and_then(|(registered, path)| Session::open(year, registered, &path));
+    let mut session = match session {
+        Ok(session) => session,
+        Err(e) => {
+            error!("{}", e);
+            process::exit(1);
+        }
+    };
+
+    println!("{} day {} ({}), parsed {}; `help` lists commands", year, day, session.day.name, session.path.display());
+    let mut lines = io::stdin().lock().lines();
+    loop {
+        print!("{}> ", session.day.name);
+        io::stdout().flush().ok();
+
+        let line = match lines.next() {
+            Some(Ok(line)) => line,
+            _ => break,
+        };
+        if line.trim().is_empty() {
+            continue
+        }
+
+        let command = match line.parse() {
+            Ok(command) => command,
+            Err(e) => {
+                println!("{}", e);
+                continue
+            }
+        };
+
+        match command {
+            Command::Part(part) => match session.solve(part) {
+                (Ok(answer), time) => println!("{}\n({:.2?})", answer, time),
+                (Err(e), _) => println!("error: {}", e),
+            },
+            Command::Input(path) if path == Path::new(runner::STDIN) => println!("commands are read from stdin, so the input has to come from a file"),
+            Command::Input(path) => match session.load(&path) {
+                Ok(()) => println!("parsed {}", path.display()),
+                Err(e) => println!("{}", e),
+            },
+            Command::Example(n) => {
+                let loaded = runner::choose_input(year, day, "", &inputs, n).and_then(|path| session.load(&path));
+                match loaded {
+                    Ok(()) => println!("parsed {}", session.path.display()),
+                    Err(e) => println!("{}", e),
+                }
+            }
+            Command::Reload => {
+                let path = session.path.clone();
+                match session.load(&path) {
+                    Ok(()) => println!("parsed {}", path.display()),
+                    Err(e) => println!("{}", e),
+                }
+            }
+            Command::Debug => {
+                let level = match log::max_level() {
+                    LevelFilter::Debug => LevelFilter::Info,
+                    _ => LevelFilter::Debug,
+                };
+                log::set_max_level(level);
+                println!("debug logging {}", if level == LevelFilter::Debug { "on" } else { "off" });
+            }
+            Command::Dump => println!("{:#?}", session.parsed()),
+            Command::Help => println!("{}", repl::HELP),
+            Command::Quit => break,
+        }
+    }
 }
\ No newline at end of file
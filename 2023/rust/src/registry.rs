@@ -0,0 +1,288 @@
+use std::{any::Any, fmt::Debug};
+
+use crate::{error::{Error, InputError}, solver::{Answer, Solver}};
+
+pub const PARTS: [u32; 2] = [1, 2];
+
+/// A day's parsed input, with its type erased so it can be handed back to the
+/// day that produced it.
+pub trait Parsed: Any + Debug {}
+
+impl<T: Any + Debug> Parsed for T {}
+
+type Parse = fn(&str) -> Result<Box<dyn Parsed>, InputError>;
+type Part = fn(&dyn Parsed) -> Result<Answer, InputError>;
+
+/// A registered day, with its solvers erased to plain function pointers so
+/// days can be looked up and run by number.
+pub struct Day {
+    pub day: u32,
+    pub name: &'static str,
+    parse: Parse,
+    parts: [Part; 2],
+}
+
+impl Day {
+    pub const fn new<S: Solver>(day: u32, name: &'static str) -> Self {
+        Day{day, name, parse: parse::<S>, parts: [part::<S, 1>, part::<S, 2>]}
+    }
+
+    pub fn parse(&self, input: &str) -> Result<Box<dyn Parsed>, Error> {
+        (self.parse)(input).map_err(|e| Error::Input(e.locate(input).on_day(self.day)))
+    }
+
+    /// Solves a part from input already parsed by this day.
+    pub fn solve_parsed(&self, part: u32, parsed: &dyn Parsed) -> Result<Answer, Error> {
+        match part {
+            1 | 2 => self.parts[part as usize - 1](parsed).map_err(|e| Error::Input(e.on_day(self.day))),
+            _ => Err(Error::NotImplemented(NotImplemented::Part(self.day, part))),
+        }
+    }
+
+    /// Parses the input and solves a single part of it.
+    pub fn solve(&self, part: u32, input: &str) -> Result<Answer, Error> {
+        if !PARTS.contains(&part) {
+            return Err(Error::NotImplemented(NotImplemented::Part(self.day, part)));
+        }
+
+        self.solve_parsed(part, self.parse(input)?.as_ref())
+    }
+}
+
+fn parse<S: Solver>(input: &str) -> Result<Box<dyn Parsed>, InputError> {
+    Ok(Box::new(S::parse(input)?))
+}
+
+fn part<S: Solver, const P: u32>(parsed: &dyn Parsed) -> Result<Answer, InputError> {
+    let input = match (parsed as &dyn Any).downcast_ref::<S::Input>() {
+        Some(input) => input,
+        None => panic!("parsed input handed to the wrong day"),
+    };
+
+    match P {
+        1 => S::solve_part_1(input),
+        _ => S::solve_part_2(input),
+    }
+}
+
+/// The days registered for a single year.
+pub struct Registry {
+    days: &'static [Day],
+}
+
+impl Registry {
+    pub const fn new(days: &'static [Day]) -> Self {
+        Registry{days}
+    }
+
+    pub fn days(&self) -> impl Iterator<Item = &Day> {
+        self.days.iter()
+    }
+
+    pub fn get(&self, day: u32) -> Option<&Day> {
+        self.days.iter().find(|d| d.day == day)
+    }
+}
+
+pub struct Year {
+    pub year: u32,
+    pub registry: &'static Registry,
+}
+
+/// Every registered year, each with its own registry of days.
+pub struct Calendar {
+    years: &'static [Year],
+}
+
+impl Calendar {
+    pub const fn new(years: &'static [Year]) -> Self {
+        Calendar{years}
+    }
+
+    pub fn years(&self) -> impl Iterator<Item = &Year> {
+        self.years.iter()
+    }
+
+    /// The most recent year that has any days registered.
+    pub fn default_year(&self) -> u32 {
+        self.years.iter()
+            .filter(|y| y.registry.days().next().is_some())
+            .map(|y| y.year)
+            .max()
+            .unwrap_or_default()
+    }
+
+    pub fn year(&self, year: u32) -> Result<&Registry, NotImplemented> {
+        match self.years.iter().find(|y| y.year == year) {
+            Some(y) => Ok(y.registry),
+            None => Err(NotImplemented::Year(year, self.years.iter().map(|y| y.year).collect())),
+        }
+    }
+
+    pub fn day(&self, year: u32, day: u32) -> Result<&Day, NotImplemented> {
+        let registry = self.year(year)?;
+        match registry.get(day) {
+            Some(d) => Ok(d),
+            None => Err(NotImplemented::Day(year, day, registry.days().map(|d| d.day).collect())),
+        }
+    }
+
+    pub fn solve(&self, year: u32, day: u32, part: u32, input: &str) -> Result<Answer, Error> {
+        self.day(year, day)?.solve(part, input).map_err(|e| e.in_year(year))
+    }
+}
+
+#[derive(Debug)]
+pub enum NotImplemented {
+    /// The requested year, and the years that are available.
+    Year(u32, Vec<u32>),
+    /// The requested year and day, and the days available in that year.
+    Day(u32, u32, Vec<u32>),
+    Part(u32, u32),
+}
+
+impl std::fmt::Display for NotImplemented {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            NotImplemented::Year(year, available) => write!(f, "year {} is not implemented (available: {:?})", year, available),
+            NotImplemented::Day(year, day, available) => write!(f, "day {} of {} is not implemented (available: {:?})", day, year, available),
+            NotImplemented::Part(day, part) => write!(f, "day {} has no part {} (parts: {:?})", day, part, PARTS),
+        }
+    }
+}
+
+/// Declares each day's module and registers its `Solution` under its day
+/// number, producing the `REGISTRY` for the enclosing year.
+#[macro_export]
+macro_rules! days {
+    ($($day:literal => $module:ident),* $(,)?) => {
+        $(pub mod $module;)*
+
+        pub static REGISTRY: $crate::registry::Registry = $crate::registry::Registry::new(&[
+            $($crate::registry::Day::new::<$module::Solution>($day, stringify!($module)),)*
+        ]);
+    };
+}
+
+/// Declares each year's module and collects their registries into the
+/// crate's `CALENDAR`.
+#[macro_export]
+macro_rules! years {
+    ($($year:literal => $module:ident),* $(,)?) => {
+        $(pub mod $module;)*
+
+        pub static CALENDAR: $crate::registry::Calendar = $crate::registry::Calendar::new(&[
+            $($crate::registry::Year{year: $year, registry: &$module::days::REGISTRY},)*
+        ]);
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::years::{y2022::days::REGISTRY, CALENDAR};
+
+    use super::*;
+
+    /// Doubles a number for part 1 and squares it for part 2.
+    struct Number;
+
+    impl Solver for Number {
+        type Input = i64;
+
+        fn parse(input: &str) -> Result<Self::Input, InputError> {
+            input.trim().parse().map_err(|e| InputError::new(e, input.trim()))
+        }
+
+        fn solve_part_1(input: &Self::Input) -> Result<Answer, InputError> {
+            Ok(Answer::Int(input * 2))
+        }
+
+        fn solve_part_2(input: &Self::Input) -> Result<Answer, InputError> {
+            Ok(Answer::Int(input * input))
+        }
+    }
+
+    static NUMBERS: Registry = Registry::new(&[Day::new::<Number>(3, "number")]);
+    static EMPTY: Registry = Registry::new(&[]);
+    static TEST_CALENDAR: Calendar = Calendar::new(&[
+        Year{year: 2021, registry: &NUMBERS},
+        Year{year: 2022, registry: &EMPTY},
+    ]);
+
+    #[test]
+    fn registry_lookup() {
+        let day = NUMBERS.get(3).expect("day 3 is registered");
+        assert_eq!((day.day, day.name), (3, "number"));
+        assert!(NUMBERS.get(4).is_none());
+        assert_eq!(day.solve(1, "7").ok(), Some(Answer::Int(14)));
+        assert_eq!(day.solve(2, "7").ok(), Some(Answer::Int(49)));
+
+        let parsed = day.parse("-3\n").ok().unwrap();
+        assert_eq!(day.solve_parsed(2, parsed.as_ref()).ok(), Some(Answer::Int(9)));
+    }
+
+    #[test]
+    fn unknown_parts() {
+        // The part is checked before the input, so a bad input doesn't hide a bad part
+        let day = NUMBERS.get(3).unwrap();
+        assert_eq!(day.solve(3, "x").unwrap_err().to_string(), "day 3 has no part 3 (parts: [1, 2])");
+        let parsed = day.parse("1").ok().unwrap();
+        assert_eq!(day.solve_parsed(0, parsed.as_ref()).unwrap_err().to_string(), "day 3 has no part 0 (parts: [1, 2])");
+    }
+
+    #[test]
+    fn input_errors_name_the_day() {
+        let e = NUMBERS.get(3).unwrap().solve(1, "\nx").unwrap_err();
+        assert_eq!(e.to_string(), "day 3, line 2, column 1: invalid digit found in string: `x`");
+    }
+
+    #[test]
+    fn calendar_lookup() {
+        assert_eq!(TEST_CALENDAR.solve(2021, 3, 1, "5").ok(), Some(Answer::Int(10)));
+        assert_eq!(TEST_CALENDAR.day(2021, 3).map(|d| d.name).ok(), Some("number"));
+        assert_eq!(TEST_CALENDAR.years().map(|y| y.year).collect::<Vec<u32>>(), vec![2021, 2022]);
+        // A year without days doesn't count as the latest
+        assert_eq!(TEST_CALENDAR.default_year(), 2021);
+    }
+
+    #[test]
+    fn unknown_years_and_days() {
+        let e = TEST_CALENDAR.year(2019).err().unwrap();
+        assert_eq!(e.to_string(), "year 2019 is not implemented (available: [2021, 2022])");
+        let e = TEST_CALENDAR.day(2021, 4).err().unwrap();
+        assert_eq!(e.to_string(), "day 4 of 2021 is not implemented (available: [3])");
+        let e = TEST_CALENDAR.day(2022, 1).err().unwrap();
+        assert_eq!(e.to_string(), "day 1 of 2022 is not implemented (available: [])");
+        let e = TEST_CALENDAR.solve(2021, 3, 3, "5").unwrap_err();
+        assert_eq!(e.to_string(), "day 3 has no part 3 (parts: [1, 2])");
+    }
+
+    #[test]
+    fn input_errors_name_the_year() {
+        let e = TEST_CALENDAR.solve(2021, 3, 1, "\nx").unwrap_err();
+        assert_eq!(e.to_string(), "2021 day 3, line 2, column 1: invalid digit found in string: `x`");
+    }
+
+    #[test]
+    fn macro_registered_years() {
+        // Years added with `new` can come after these, so only what's known to exist is pinned
+        assert!(CALENDAR.year(2022).is_ok());
+        assert!(CALENDAR.year(2023).is_ok());
+        assert_eq!(CALENDAR.day(2022, 1).map(|d| d.name).ok(), Some("one"));
+
+        let years: Vec<u32> = CALENDAR.years().map(|y| y.year).collect();
+        assert!(years.windows(2).all(|pair| pair[0] < pair[1]), "{:?}", years);
+        assert!(CALENDAR.default_year() >= 2022);
+    }
+
+    #[test]
+    fn macro_registered_days() {
+        // Days are registered under their number and named after their module
+        let days: Vec<(u32, &str)> = REGISTRY.days().map(|d| (d.day, d.name)).take(3).collect();
+        assert_eq!(days, vec![(1, "one"), (2, "two"), (3, "three")]);
+        assert_eq!(REGISTRY.get(15).map(|d| d.name), Some("fifteen"));
+        // `new` adds days in order, so whatever has been added since they stay sorted
+        let days: Vec<u32> = REGISTRY.days().map(|d| d.day).collect();
+        assert!(days.windows(2).all(|pair| pair[0] < pair[1]), "{:?}", days);
+    }
+}
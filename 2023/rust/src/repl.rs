@@ -0,0 +1,155 @@
+use std::{fmt::Display, path::{Path, PathBuf}, str::FromStr, time::{Duration, Instant}};
+
+use crate::{error::Error, registry::{Day, Parsed}, runner, solver::Answer};
+
+/// A line typed at the REPL.
+#[derive(Debug, PartialEq, Eq)]
+pub enum Command {
+    Part(u32),
+    /// Switch to the input in this file.
+    Input(PathBuf),
+    /// Switch to a numbered example input.
+    Example(u32),
+    /// Re-read and re-parse the current input.
+    Reload,
+    /// Toggle debug logging.
+    Debug,
+    /// Show the parsed input.
+    Dump,
+    Help,
+    Quit,
+}
+
+pub const HELP: &str = "\
+1, 2 or part <n>   solve a part
+input <path>       switch to the input in a file
+example <n>        switch to an example input
+reload             re-read the current input
+debug              toggle debug logging
+dump               show the parsed input
+help               show this help
+quit               leave";
+
+#[derive(Debug)]
+pub struct ParseCommandError {
+    pub reason: String,
+}
+
+impl Display for ParseCommandError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.reason)
+    }
+}
+
+impl FromStr for Command {
+    type Err = ParseCommandError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (command, argument) = match s.trim().split_once(char::is_whitespace) {
+            Some((command, argument)) => (command, Some(argument.trim())),
+            None => (s.trim(), None),
+        };
+
+        let number = |argument: Option<&str>| match argument.map(str::parse::<u32>) {
+            Some(Ok(n)) => Ok(n),
+            Some(Err(e)) => Err(ParseCommandError{reason: format!("`{}` {}: {}", command, argument.unwrap_or_default(), e)}),
+            None => Err(ParseCommandError{reason: format!("`{}` needs a number", command)}),
+        };
+
+        match (command, argument) {
+            ("part", argument) => Ok(Command::Part(number(argument)?)),
+            ("example", argument) => Ok(Command::Example(number(argument)?)),
+            ("input", Some(path)) => Ok(Command::Input(PathBuf::from(path))),
+            ("input", None) => Err(ParseCommandError{reason: String::from("`input` needs a path")}),
+            ("reload", None) => Ok(Command::Reload),
+            ("debug", None) => Ok(Command::Debug),
+            ("dump", None) => Ok(Command::Dump),
+            ("help" | "?", None) => Ok(Command::Help),
+            ("quit" | "exit", None) => Ok(Command::Quit),
+            (part, None) if part.parse::<u32>().is_ok() => Ok(Command::Part(number(Some(part))?)),
+            _ => Err(ParseCommandError{reason: format!("unknown command `{}` (try `help`)", s.trim())}),
+        }
+    }
+}
+
+/// A day's input, parsed once and kept for as many runs as are asked for.
+pub struct Session<'a> {
+    pub year: u32,
+    pub day: &'a Day,
+    pub path: PathBuf,
+    parsed: Box<dyn Parsed>,
+}
+
+impl<'a> Session<'a> {
+    pub fn open(year: u32, day: &'a Day, path: &Path) -> Result<Self, String> {
+        let parsed = Self::read(year, day, path)?;
+        Ok(Session{year, day, path: path.to_path_buf(), parsed})
+    }
+
+    /// Switches to the input at `path`. If it can't be read or parsed, the
+    /// current input is kept.
+    pub fn load(&mut self, path: &Path) -> Result<(), String> {
+        self.parsed = Self::read(self.year, self.day, path)?;
+        self.path = path.to_path_buf();
+        Ok(())
+    }
+
+    fn read(year: u32, day: &Day, path: &Path) -> Result<Box<dyn Parsed>, String> {
+        let input = runner::read_input(path)?;
+        day.parse(&input).map_err(|e| e.in_year(year).to_string())
+    }
+
+    pub fn solve(&self, part: u32) -> (Result<Answer, Error>, Duration) {
+        let start = Instant::now();
+        let answer = self.day.solve_parsed(part, self.parsed.as_ref()).map_err(|e| e.in_year(self.year));
+        (answer, start.elapsed())
+    }
+
+    pub fn parsed(&self) -> &dyn Parsed {
+        self.parsed.as_ref()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{env, fs, process};
+
+    use crate::years::CALENDAR;
+
+    use super::*;
+
+    #[test]
+    fn parse_commands() {
+        assert_eq!("1".parse::<Command>().unwrap(), Command::Part(1));
+        assert_eq!(" part 2 ".parse::<Command>().unwrap(), Command::Part(2));
+        assert_eq!("input /tmp/day 1.txt".parse::<Command>().unwrap(), Command::Input(PathBuf::from("/tmp/day 1.txt")));
+        assert_eq!("example 2".parse::<Command>().unwrap(), Command::Example(2));
+        assert_eq!("dump".parse::<Command>().unwrap(), Command::Dump);
+        assert_eq!("exit".parse::<Command>().unwrap(), Command::Quit);
+
+        assert_eq!("example".parse::<Command>().unwrap_err().reason, "`example` needs a number");
+        assert!("part two".parse::<Command>().is_err());
+        assert!("dump now".parse::<Command>().is_err());
+        assert!("jump".parse::<Command>().is_err());
+    }
+
+    #[test]
+    fn session_keeps_input_on_bad_load() {
+        let dir = env::temp_dir().join(format!("aoc-repl-{}", process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let (good, bad) = (dir.join("good.txt"), dir.join("bad.txt"));
+        fs::write(&good, "1000\n2000\n\n4000\n").unwrap();
+        fs::write(&bad, "1000\nx\n").unwrap();
+
+        let mut session = Session::open(2022, CALENDAR.day(2022, 1).unwrap(), &good).unwrap();
+        assert_eq!(session.solve(1).0.ok(), Some(Answer::Int(4000)));
+        assert_eq!(format!("{:?}", session.parsed()), "[4000, 3000]");
+
+        let e = session.load(&bad).unwrap_err();
+        assert_eq!(e, "2022 day 1, line 2, column 1: invalid digit found in string: `x`");
+        assert_eq!(session.path, good);
+        assert_eq!(session.solve(2).0.ok(), Some(Answer::Int(7000)));
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
\ No newline at end of file
@@ -0,0 +1,196 @@
+use std::{fmt::Display, str::FromStr, time::Duration};
+
+use crate::runner::{DayError, DayResult};
+
+/// How results are written out.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Format {
+    /// Human readable, as a table or a bare answer.
+    Text,
+    Json,
+    Csv,
+}
+
+impl Display for Format {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Format::Text => write!(f, "text"),
+            Format::Json => write!(f, "json"),
+            Format::Csv => write!(f, "csv"),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct ParseFormatError {
+    pub reason: String,
+}
+
+impl Display for ParseFormatError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.reason)
+    }
+}
+
+impl FromStr for Format {
+    type Err = ParseFormatError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            "csv" => Ok(Format::Csv),
+            _ => Err(ParseFormatError{reason: format!("unknown output format `{}` (expected json, csv or text)", s)}),
+        }
+    }
+}
+
+/// One part's result flattened for reporting. A day that couldn't be run
+/// at all is a single row with no part.
+struct Row {
+    year: u32,
+    day: u32,
+    part: Option<u32>,
+    answer: Result<String, String>,
+    /// Parsing is shared by every part of a day, so each part carries the day's parse time.
+    parse: Option<Duration>,
+    solve: Option<Duration>,
+}
+
+fn rows(results: &[DayResult]) -> Vec<Row> {
+    let mut rows = Vec::new();
+
+    for result in results {
+        let (year, day) = (result.year, result.day);
+        match &result.run {
+            Ok(run) => for part in &run.parts {
+                let answer = part.answer.as_ref().map(|a| a.to_string()).map_err(|e| e.to_string());
+                rows.push(Row{year, day, part: Some(part.part), answer, parse: Some(run.parse), solve: Some(part.time)});
+            }
+            Err(DayError::Invalid(e)) => rows.push(Row{year, day, part: None, answer: Err(e.to_string()), parse: None, solve: None}),
+            Err(DayError::Missing(e)) => rows.push(Row{year, day, part: None, answer: Err(e.clone()), parse: None, solve: None}),
+        }
+    }
+
+    rows
+}
+
+/// A JSON array with an object per row. Answers are always strings, and times
+/// are whole nanoseconds. Fields that don't apply are `null`.
+pub fn json(results: &[DayResult]) -> String {
+    let number = |n: Option<u128>| n.map(|n| n.to_string()).unwrap_or(String::from("null"));
+    let string = |s: Option<&String>| s.map(|s| json_string(s)).unwrap_or(String::from("null"));
+
+    let objects: Vec<String> = rows(results).iter().map(|row| format!(
+        "{{\"year\":{},\"day\":{},\"part\":{},\"answer\":{},\"error\":{},\"parse_ns\":{},\"solve_ns\":{}}}",
+        row.year,
+        row.day,
+        number(row.part.map(u128::from)),
+        string(row.answer.as_ref().ok()),
+        string(row.answer.as_ref().err()),
+        number(row.parse.map(|d| d.as_nanos())),
+        number(row.solve.map(|d| d.as_nanos())),
+    )).collect();
+
+    format!("[{}]", objects.join(","))
+}
+
+fn json_string(s: &str) -> String {
+    let mut escaped = String::from("\"");
+    for c in s.chars() {
+        match c {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            c if c.is_control() => escaped.push_str(&format!("\\u{:04x}", c as u32)),
+            c => escaped.push(c),
+        }
+    }
+    escaped.push('"');
+    escaped
+}
+
+/// CSV with a header row, times in whole nanoseconds and empty fields where
+/// a value doesn't apply.
+pub fn csv(results: &[DayResult]) -> String {
+    let number = |n: Option<u128>| n.map(|n| n.to_string()).unwrap_or_default();
+
+    let mut csv = String::from("year,day,part,answer,error,parse_ns,solve_ns\n");
+    for row in rows(results) {
+        csv.push_str(&format!(
+            "{},{},{},{},{},{},{}\n",
+            row.year,
+            row.day,
+            number(row.part.map(u128::from)),
+            csv_field(row.answer.as_ref().map(String::as_str).unwrap_or_default()),
+            csv_field(row.answer.as_ref().err().map(String::as_str).unwrap_or_default()),
+            number(row.parse.map(|d| d.as_nanos())),
+            number(row.solve.map(|d| d.as_nanos())),
+        ));
+    }
+
+    csv
+}
+
+/// Quotes a field if it contains anything that would otherwise break the row.
+fn csv_field(s: &str) -> String {
+    match s.contains([',', '"', '\n', '\r']) {
+        true => format!("\"{}\"", s.replace('"', "\"\"")),
+        false => s.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{runner::{DayRun, PartResult}, solver::Answer};
+
+    use super::*;
+
+    fn results() -> Vec<DayResult> {
+        let part = |part, answer, nanos| PartResult{part, answer: Ok(answer), time: Duration::from_nanos(nanos), allocs: None};
+        vec![
+            DayResult{year: 2022, day: 1, run: Ok(DayRun{parse: Duration::from_nanos(100), parse_allocs: None, parts: vec![
+                part(1, Answer::Int(24000), 20),
+                part(2, Answer::Text(String::from("a \"quoted\", answer")), 30),
+            ]})},
+            DayResult{year: 2022, day: 10, run: Ok(DayRun{parse: Duration::from_nanos(5), parse_allocs: None, parts: vec![
+                part(2, Answer::Art(vec![String::from("#.."), String::from(".#.")]), 7),
+            ]})},
+            DayResult{year: 2022, day: 2, run: Err(DayError::Missing(String::from("no input found")))},
+        ]
+    }
+
+    #[test]
+    fn parse_format() {
+        assert_eq!("json".parse::<Format>().unwrap(), Format::Json);
+        assert_eq!("csv".parse::<Format>().unwrap(), Format::Csv);
+        assert_eq!("text".parse::<Format>().unwrap(), Format::Text);
+        assert!("xml".parse::<Format>().is_err());
+    }
+
+    #[test]
+    fn json_rows() {
+        assert_eq!(json(&results()), concat!(
+            "[",
+            r##"{"year":2022,"day":1,"part":1,"answer":"24000","error":null,"parse_ns":100,"solve_ns":20},"##,
+            r##"{"year":2022,"day":1,"part":2,"answer":"a \"quoted\", answer","error":null,"parse_ns":100,"solve_ns":30},"##,
+            r##"{"year":2022,"day":10,"part":2,"answer":"#..\n.#.","error":null,"parse_ns":5,"solve_ns":7},"##,
+            r##"{"year":2022,"day":2,"part":null,"answer":null,"error":"no input found","parse_ns":null,"solve_ns":null}"##,
+            "]",
+        ));
+        assert_eq!(json(&[]), "[]");
+    }
+
+    #[test]
+    fn csv_rows() {
+        assert_eq!(csv(&results()), concat!(
+            "year,day,part,answer,error,parse_ns,solve_ns\n",
+            "2022,1,1,24000,,100,20\n",
+            "2022,1,2,\"a \"\"quoted\"\", answer\",,100,30\n",
+            "2022,10,2,\"#..\n.#.\",,5,7\n",
+            "2022,2,,,no input found,,\n",
+        ));
+    }
+}
\ No newline at end of file
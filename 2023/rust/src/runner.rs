@@ -0,0 +1,446 @@
+use std::{
+    cell::{Cell, RefCell},
+    fmt::Display,
+    fs,
+    io::{self, Read},
+    panic::{self, AssertUnwindSafe},
+    path::{Path, PathBuf},
+    sync::{atomic::{AtomicUsize, Ordering}, mpsc, Once},
+    thread,
+    time::{Duration, Instant, SystemTime},
+};
+
+use crate::{alloc::{self, Allocs}, error::Error, registry::{Calendar, Day, PARTS}, solver::{self, Answer, Cancelled}};
+
+pub struct PartResult {
+    pub part: u32,
+    pub answer: Result<Answer, Error>,
+    pub time: Duration,
+    /// What solving allocated, when allocations are counted.
+    pub allocs: Option<Allocs>,
+}
+
+/// A day whose input parsed, with how long parsing took and each part's result.
+pub struct DayRun {
+    pub parse: Duration,
+    /// What parsing allocated, when allocations are counted.
+    pub parse_allocs: Option<Allocs>,
+    pub parts: Vec<PartResult>,
+}
+
+pub enum DayError {
+    /// The input couldn't be loaded, so the day was skipped.
+    Missing(String),
+    /// The input couldn't be parsed.
+    Invalid(Error),
+}
+
+pub struct DayResult {
+    pub year: u32,
+    pub day: u32,
+    pub run: Result<DayRun, DayError>,
+}
+
+impl DayResult {
+    /// Whether the input or any part failed, including by panicking or timing
+    /// out. A day skipped for want of an input hasn't failed.
+    pub fn failed(&self) -> bool {
+        match &self.run {
+            Ok(run) => run.parts.iter().any(|part| part.answer.is_err()),
+            Err(DayError::Invalid(_)) => true,
+            Err(DayError::Missing(_)) => false,
+        }
+    }
+}
+
+/// Where example inputs live, alongside the inputs directory.
+pub const EXAMPLES: &str = "examples";
+
+pub enum Source {
+    /// The real puzzle input.
+    Input,
+    /// A numbered sample input from the puzzle text.
+    Example(u32),
+}
+
+/// Where a day's input is expected to live under `dir`: `<year>/<day>.txt`
+/// for puzzle inputs and `<year>/<day>-<n>.txt` for examples.
+pub fn input_path(dir: &Path, year: u32, day: u32, source: &Source) -> PathBuf {
+    let file = match source {
+        Source::Input => format!("{}.txt", day),
+        Source::Example(n) => format!("{}-{}.txt", day, n),
+    };
+
+    dir.join(year.to_string()).join(file)
+}
+
+/// Looks for a day's input under `dir`, and when `dir` is relative, under
+/// the crate's own directory too so runs work from any working directory.
+pub fn find_input(dir: &Path, year: u32, day: u32, source: &Source) -> Result<PathBuf, NotFound> {
+    let mut searched = vec![input_path(dir, year, day, source)];
+    if dir.is_relative() {
+        searched.push(input_path(&Path::new(env!("CARGO_MANIFEST_DIR")).join(dir), year, day, source));
+    }
+
+    match searched.iter().find(|path| path.is_file()) {
+        Some(path) => Ok(path.clone()),
+        None => Err(NotFound{year, day, searched}),
+    }
+}
+
+/// No input was found for a day in any of the places it could be.
+pub struct NotFound {
+    pub year: u32,
+    pub day: u32,
+    pub searched: Vec<PathBuf>,
+}
+
+impl Display for NotFound {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let searched: Vec<String> = self.searched.iter().map(|p| p.display().to_string()).collect();
+        write!(f, "no input found for {} day {} (searched {})", self.year, self.day, searched.join(", "))
+    }
+}
+
+/// The path that stands for standard input.
+pub const STDIN: &str = "-";
+
+/// Reads the contents of an input file, or of standard input for [`STDIN`].
+pub fn read_input(path: &Path) -> Result<String, String> {
+    read_input_from(path, io::stdin())
+}
+
+/// Like [`read_input`], with `stdin` standing in for standard input.
+fn read_input_from(path: &Path, stdin: impl Read) -> Result<String, String> {
+    let (name, contents) = match path == Path::new(STDIN) {
+        true => (String::from("stdin"), io::read_to_string(stdin)),
+        false => (path.display().to_string(), fs::read_to_string(path)),
+    };
+
+    contents.map_err(|e| format!("{}: {}", name, e))
+}
+
+/// Where the input for a run comes from: `file` if one was given, otherwise
+/// the numbered example, or the real input under `inputs` when `example` is 0.
+pub fn choose_input(year: u32, day: u32, file: &str, inputs: &str, example: u32) -> Result<PathBuf, String> {
+    let path = match (file, example) {
+        ("", 0) => find_input(Path::new(inputs), year, day, &Source::Input),
+        ("", n) => find_input(Path::new(EXAMPLES), year, day, &Source::Example(n)),
+        (file, _) => Ok(PathBuf::from(file)),
+    };
+
+    path.map_err(|e| e.to_string())
+}
+
+/// The input for a run: `text` if it was given, even empty, otherwise what's
+/// at [`choose_input`].
+pub fn load_input(year: u32, day: u32, file: &str, inputs: &str, example: u32, text: Option<&str>) -> Result<String, String> {
+    match (text, file, example) {
+        (None, _, _) => choose_input(year, day, file, inputs, example).and_then(|path| read_input(&path)),
+        (Some(text), "", 0) => Ok(text.to_string()),
+        _ => Err(String::from("--input-text can't be used with an input file or --example")),
+    }
+}
+
+/// Notices when a file changes by polling its modification time.
+pub struct Watcher {
+    path: PathBuf,
+    modified: Option<SystemTime>,
+}
+
+impl Watcher {
+    pub fn new(path: &Path) -> Self {
+        Watcher{path: path.to_path_buf(), modified: None}
+    }
+
+    /// Whether the file has changed since the last call. The first call counts
+    /// as a change if the file exists, so the initial run happens straight away.
+    pub fn changed(&mut self) -> bool {
+        let modified = fs::metadata(&self.path).and_then(|m| m.modified()).ok();
+        match modified != self.modified {
+            true => {
+                self.modified = modified;
+                modified.is_some()
+            }
+            false => false,
+        }
+    }
+}
+
+/// Parses the input once, then solves each of `parts` from it, timing each
+/// phase. A panic in any phase is caught and reported as that phase's error,
+/// as is running past `limit`, if there is one.
+pub fn run_day(year: u32, day: &Day, input: &str, parts: &[u32], limit: Option<Duration>) -> Result<DayRun, Error> {
+    let start = Instant::now();
+    let (parsed, parse_allocs) = catch(limit, || alloc::measure(|| day.parse(input))).map_err(|e| e.in_year(year))?;
+    let parsed = parsed.map_err(|e| e.in_year(year))?;
+    let parse = start.elapsed();
+
+    let mut results = Vec::new();
+    for &part in parts {
+        let start = Instant::now();
+        let (answer, allocs) = match catch(limit, || alloc::measure(|| day.solve_parsed(part, parsed.as_ref()))) {
+            Ok((answer, allocs)) => (answer, allocs),
+            Err(e) => (Err(e), None),
+        };
+        results.push(PartResult{part, answer: answer.map_err(|e| e.in_year(year)), time: start.elapsed(), allocs});
+    }
+
+    Ok(DayRun{parse, parse_allocs, parts: results})
+}
+
+thread_local! {
+    /// Set while this thread is running a solver under [`catch`].
+    static CATCHING: Cell<bool> = const { Cell::new(false) };
+    /// Where and why the solver panicked, recorded by the panic hook.
+    static PANIC: RefCell<Option<String>> = const { RefCell::new(None) };
+}
+
+static HOOK: Once = Once::new();
+
+/// Runs `f`, turning a panic into an error instead of unwinding further. With
+/// a `limit`, `f` is cancelled at its first [`solver::checkpoint`] past it, and
+/// fails even if it finishes when it has no checkpoints to stop at.
+fn catch<T>(limit: Option<Duration>, f: impl FnOnce() -> T) -> Result<T, Error> {
+    // Panics being caught are reported with the results, so they're recorded
+    // rather than printed. Any other panic still goes to the original hook.
+    HOOK.call_once(|| {
+        let default = panic::take_hook();
+        panic::set_hook(Box::new(move |info| match CATCHING.with(Cell::get) {
+            true => {
+                let message = match (info.payload().downcast_ref::<&str>(), info.payload().downcast_ref::<String>()) {
+                    (Some(s), _) => s.to_string(),
+                    (_, Some(s)) => s.clone(),
+                    _ => String::from("with a non-string payload"),
+                };
+                let at = info.location().map(|l| format!("at {}:{}: ", l.file(), l.line())).unwrap_or_default();
+                PANIC.with(|p| *p.borrow_mut() = Some(format!("{}{}", at, message)));
+            }
+            false => default(info),
+        }));
+    });
+
+    let start = Instant::now();
+    CATCHING.with(|c| c.set(true));
+    solver::set_deadline(limit.map(|limit| start + limit));
+    let result = panic::catch_unwind(AssertUnwindSafe(f));
+    solver::set_deadline(None);
+    CATCHING.with(|c| c.set(false));
+
+    match (result, limit) {
+        (Err(payload), Some(limit)) if payload.is::<Cancelled>() => Err(Error::TimedOut(limit)),
+        (Err(_), _) => Err(Error::Panicked(PANIC.with(|p| p.borrow_mut().take()).unwrap_or_default())),
+        (Ok(_), Some(limit)) if start.elapsed() > limit => Err(Error::TimedOut(limit)),
+        (Ok(t), _) => Ok(t),
+    }
+}
+
+/// Runs both parts of every registered day against its input in `inputs`,
+/// spreading the days over `threads` worker threads. Results come back in
+/// calendar order however the days were scheduled.
+pub fn run_all(calendar: &Calendar, inputs: &Path, threads: usize, limit: Option<Duration>) -> Vec<DayResult> {
+    let days: Vec<(u32, &Day)> = calendar.years()
+        .flat_map(|year| year.registry.days().map(move |day| (year.year, day)))
+        .collect();
+
+    let next = AtomicUsize::new(0);
+    let (sender, receiver) = mpsc::channel();
+    thread::scope(|scope| {
+        for _ in 0..threads.clamp(1, days.len().max(1)) {
+            let (days, next, sender) = (&days, &next, sender.clone());
+            scope.spawn(move || {
+                while let Some(&(year, day)) = days.get(next.fetch_add(1, Ordering::Relaxed)) {
+                    let input = find_input(inputs, year, day.day, &Source::Input)
+                        .map_err(|e| e.to_string())
+                        .and_then(|path| read_input(&path));
+
+                    let run = match input {
+                        Ok(input) => run_day(year, day, &input, &PARTS, limit).map_err(DayError::Invalid),
+                        Err(e) => Err(DayError::Missing(e)),
+                    };
+
+                    sender.send(DayResult{year, day: day.day, run}).unwrap();
+                }
+            });
+        }
+    });
+    drop(sender);
+
+    let mut results: Vec<DayResult> = receiver.into_iter().collect();
+    results.sort_by_key(|r| (r.year, r.day));
+    results
+}
+
+/// How many days to run at once by default: one per available core.
+pub fn default_threads() -> usize {
+    thread::available_parallelism().map(|n| n.get()).unwrap_or(1)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{env, process};
+
+    use crate::{error::InputError, solver::Solver, years::CALENDAR};
+
+    use super::*;
+
+    #[test]
+    fn input_paths() {
+        assert_eq!(input_path(Path::new("inputs"), 2022, 3, &Source::Input), Path::new("inputs/2022/3.txt"));
+        // Examples are kept apart from inputs, under their own directory
+        assert_eq!(input_path(Path::new(EXAMPLES), 2022, 3, &Source::Example(2)), Path::new("examples/2022/3-2.txt"));
+    }
+
+    #[test]
+    fn missing_input_lists_searched_paths() {
+        let e = find_input(Path::new("no-such-inputs"), 2022, 3, &Source::Input).expect_err("input is missing");
+        assert_eq!(e.searched.len(), 2);
+        assert_eq!(e.searched[0], Path::new("no-such-inputs/2022/3.txt"));
+        assert!(e.searched[1].ends_with("no-such-inputs/2022/3.txt"));
+        assert!(e.to_string().starts_with("no input found for 2022 day 3 (searched no-such-inputs/2022/3.txt, "));
+    }
+
+    #[test]
+    fn input_sources() {
+        let path = env::temp_dir().join(format!("aoc-input-{}.txt", process::id()));
+        fs::write(&path, "1000\n").unwrap();
+        let file = path.to_str().unwrap();
+
+        assert_eq!(load_input(2022, 1, file, "no-such-inputs", 0, None), Ok(String::from("1000\n")));
+        assert_eq!(read_input_from(Path::new(STDIN), "2000\n".as_bytes()), Ok(String::from("2000\n")));
+        assert_eq!(load_input(2022, 1, "", "no-such-inputs", 0, Some("3000\n")), Ok(String::from("3000\n")));
+        // Text given as nothing at all is still given, not looked up as a file
+        assert_eq!(load_input(2022, 1, "", "no-such-inputs", 0, Some("")), Ok(String::new()));
+
+        let e = load_input(2022, 1, file, "no-such-inputs", 0, Some("")).unwrap_err();
+        assert_eq!(e, "--input-text can't be used with an input file or --example");
+        let e = load_input(2022, 1, "", "no-such-inputs", 2, Some("1000")).unwrap_err();
+        assert_eq!(e, "--input-text can't be used with an input file or --example");
+        fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn input_errors() {
+        let path = env::temp_dir().join(format!("aoc-bad-input-{}.txt", process::id()));
+        fs::write(&path, b"1000\n\xff\n").unwrap();
+        let from_file = read_input_from(&path, io::empty()).unwrap_err();
+        let from_stdin = read_input_from(Path::new(STDIN), b"1000\n\xff\n".as_slice()).unwrap_err();
+        fs::remove_file(&path).unwrap();
+
+        // Stdin fails like a file does, named as stdin
+        assert_eq!(from_file, format!("{}: stream did not contain valid UTF-8", path.display()));
+        assert_eq!(from_stdin, "stdin: stream did not contain valid UTF-8");
+        assert!(load_input(2022, 1, "no-such-file.txt", "inputs", 0, None).unwrap_err().starts_with("no-such-file.txt: "));
+
+        // A bad input is located the same wherever it came from
+        let path = env::temp_dir().join(format!("aoc-unparsable-input-{}.txt", process::id()));
+        fs::write(&path, "1000\nx\n").unwrap();
+        let from_file = load_input(2022, 1, path.to_str().unwrap(), "no-such-inputs", 0, None).unwrap();
+        let from_text = load_input(2022, 1, "", "no-such-inputs", 0, Some("1000\nx\n")).unwrap();
+        fs::remove_file(&path).unwrap();
+        let solve = |input: &str| CALENDAR.solve(2022, 1, 1, input).unwrap_err().to_string();
+        assert_eq!(solve(&from_file), solve(&from_text));
+        assert!(solve(&from_text).starts_with("2022 day 1, line 2, column 1: "), "{}", solve(&from_text));
+    }
+
+    struct Panics;
+
+    impl Solver for Panics {
+        type Input = u32;
+
+        fn parse(input: &str) -> Result<Self::Input, InputError> {
+            match input {
+                "parse" => panic!("no cargo on stack"),
+                _ => Ok(1),
+            }
+        }
+
+        fn solve_part_1(_: &Self::Input) -> Result<Answer, InputError> {
+            Ok(Answer::Int(1))
+        }
+
+        fn solve_part_2(_: &Self::Input) -> Result<Answer, InputError> {
+            panic!("no ending position found for {}", 2)
+        }
+    }
+
+    #[test]
+    fn panics_are_failures() {
+        let day = Day::new::<Panics>(5, "panics");
+
+        let run = run_day(2022, &day, "solve", &PARTS, None).ok().unwrap();
+        assert_eq!(run.parts[0].answer.as_ref().ok(), Some(&Answer::Int(1)));
+        let e = run.parts[1].answer.as_ref().expect_err("part 2 panics").to_string();
+        assert!(e.starts_with("panicked at src/runner.rs:"), "{}", e);
+        assert!(e.ends_with(": no ending position found for 2"), "{}", e);
+
+        let e = run_day(2022, &day, "parse", &PARTS, None).err().unwrap().to_string();
+        assert!(e.ends_with(": no cargo on stack"), "{}", e);
+    }
+
+    #[test]
+    fn failed_days() {
+        let day = Day::new::<Panics>(5, "panics");
+        let result = |run| DayResult{year: 2022, day: 5, run};
+
+        assert!(!result(run_day(2022, &day, "solve", &[1], None).map_err(DayError::Invalid)).failed());
+        assert!(result(run_day(2022, &day, "solve", &PARTS, None).map_err(DayError::Invalid)).failed());
+        assert!(result(run_day(2022, &day, "parse", &PARTS, None).map_err(DayError::Invalid)).failed());
+        assert!(!result(Err(DayError::Missing(String::from("no input")))).failed());
+    }
+
+    struct Slow;
+
+    impl Solver for Slow {
+        type Input = ();
+
+        fn parse(_: &str) -> Result<Self::Input, InputError> {
+            Ok(())
+        }
+
+        /// Loops forever, but checks in as it goes.
+        fn solve_part_1(_: &Self::Input) -> Result<Answer, InputError> {
+            loop {
+                solver::checkpoint();
+            }
+        }
+
+        /// Finishes, but only after the limit and without checking in.
+        fn solve_part_2(_: &Self::Input) -> Result<Answer, InputError> {
+            thread::sleep(Duration::from_millis(50));
+            Ok(Answer::Int(2))
+        }
+    }
+
+    #[test]
+    fn parts_time_out() {
+        let day = Day::new::<Slow>(11, "slow");
+        let limit = Duration::from_millis(10);
+
+        let run = run_day(2022, &day, "", &PARTS, Some(limit)).ok().unwrap();
+        assert!(matches!(run.parts[0].answer, Err(Error::TimedOut(l)) if l == limit));
+        assert!(matches!(run.parts[1].answer, Err(Error::TimedOut(l)) if l == limit));
+
+        // Without a limit the checkpoint never fires
+        let run = run_day(2022, &day, "", &[2], None).ok().unwrap();
+        assert_eq!(run.parts[0].answer.as_ref().ok(), Some(&Answer::Int(2)));
+    }
+
+    #[test]
+    fn watch_for_changes() {
+        let path = env::temp_dir().join(format!("aoc-watch-{}.txt", process::id()));
+        let mut watcher = Watcher::new(&path);
+        assert!(!watcher.changed());
+
+        fs::write(&path, "1\n").unwrap();
+        assert!(watcher.changed());
+        assert!(!watcher.changed());
+
+        let file = fs::File::options().write(true).open(&path).unwrap();
+        file.set_modified(SystemTime::now() + Duration::from_secs(1)).unwrap();
+        assert!(watcher.changed());
+
+        fs::remove_file(&path).unwrap();
+        assert!(!watcher.changed());
+    }
+}
\ No newline at end of file
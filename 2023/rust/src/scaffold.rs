@@ -0,0 +1,199 @@
+use std::{fs, path::{Path, PathBuf}};
+
+use crate::runner::{self, Source};
+
+const ONES: [&str; 20] = [
+    "zero", "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+    "ten", "eleven", "twelve", "thirteen", "fourteen", "fifteen", "sixteen", "seventeen", "eighteen", "nineteen",
+];
+
+/// The module name for a day, spelled out as the existing days are.
+pub fn day_name(day: u32) -> Option<String> {
+    match day {
+        1..=19 => Some(ONES[day as usize].to_string()),
+        20 => Some(String::from("twenty")),
+        21..=25 => Some(format!("twenty_{}", ONES[day as usize - 20])),
+        _ => None,
+    }
+}
+
+/// A new day's module: a parser that keeps the input's lines and parts that
+/// count them, with an example test for each part to fill in.
+pub fn template() -> String {
+    String::from(r#"use crate::{error::InputError, solver::{Answer, Solver}};
+
+pub struct Solution;
+
+impl Solver for Solution {
+    type Input = Vec<String>;
+
+    fn parse(input: &str) -> Result<Self::Input, InputError> {
+        Ok(input.lines().map(String::from).collect())
+    }
+
+    fn solve_part_1(input: &Self::Input) -> Result<Answer, InputError> {
+        Ok(input.len().into())
+    }
+
+    fn solve_part_2(input: &Self::Input) -> Result<Answer, InputError> {
+        Ok(input.len().into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "";
+
+    #[test]
+    fn example_part_1() {
+        let input = Solution::parse(EXAMPLE).unwrap();
+        assert_eq!(Solution::solve_part_1(&input), Ok(Answer::Int(0)));
+    }
+
+    #[test]
+    fn example_part_2() {
+        let input = Solution::parse(EXAMPLE).unwrap();
+        assert_eq!(Solution::solve_part_2(&input), Ok(Answer::Int(0)));
+    }
+}"#)
+}
+
+/// Adds `key => module` to a `days!` or `years!` invocation, keeping the
+/// entries in order. Fails if `key` is already registered.
+pub fn register(source: &str, key: u32, module: &str) -> Result<String, String> {
+    let (head, body) = match source.split_once('{') {
+        Some((head, rest)) => match rest.rsplit_once('}') {
+            Some((body, _)) => (head.trim_end(), body),
+            None => return Err(String::from("no closing `}`")),
+        },
+        None => return Err(String::from("no opening `{`")),
+    };
+
+    let mut entries = Vec::new();
+    for entry in body.split(',').map(str::trim).filter(|e| !e.is_empty()) {
+        let (k, m) = match entry.split_once("=>").map(|(k, m)| (k.trim().parse::<u32>(), m.trim())) {
+            Some((Ok(k), m)) => (k, m.to_string()),
+            _ => return Err(format!("unexpected entry `{}`", entry)),
+        };
+        if k == key {
+            return Err(format!("{} is already registered as `{}`", key, m));
+        }
+        entries.push((k, m));
+    }
+    entries.push((key, module.to_string()));
+    entries.sort();
+
+    let mut registered = format!("{} {{\n", head);
+    for (k, m) in entries {
+        registered.push_str(&format!("    {} => {},\n", k, m));
+    }
+    registered.push('}');
+
+    Ok(registered)
+}
+
+/// Generates a day's module under `src`, registers it, and creates an empty
+/// input for it under `inputs`, adding the year too if it's new. Returns the
+/// files written. Nothing is written if the day already exists.
+pub fn new_day(src: &Path, inputs: &Path, year: u32, day: u32) -> Result<Vec<PathBuf>, String> {
+    let name = day_name(day).ok_or(format!("day {} is not in the calendar (1 to 25)", day))?;
+
+    let years_path = src.join("years.rs");
+    let year_path = src.join(format!("years/y{}.rs", year));
+    let days_path = src.join(format!("years/y{}/days.rs", year));
+    let module_path = src.join(format!("years/y{}/days/{}.rs", year, name));
+
+    if module_path.exists() {
+        return Err(format!("{} already exists", module_path.display()));
+    }
+
+    let read = |path: &Path| fs::read_to_string(path).map_err(|e| format!("{}: {}", path.display(), e));
+    let write = |path: &Path, contents: &str| {
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir).map_err(|e| format!("{}: {}", dir.display(), e))?;
+        }
+        fs::write(path, contents).map_err(|e| format!("{}: {}", path.display(), e))
+    };
+
+    // Every edit is worked out before anything is written, so a refusal leaves the tree as it was
+    let new_year = !days_path.exists();
+    let days = match new_year {
+        true => String::from("crate::days! {}"),
+        false => read(&days_path)?,
+    };
+    let days = register(&days, day, &name).map_err(|e| format!("{}: day {}", days_path.display(), e))?;
+    let years = match new_year {
+        true => Some(register(&read(&years_path)?, year, &format!("y{}", year)).map_err(|e| format!("{}: year {}", years_path.display(), e))?),
+        false => None,
+    };
+
+    let mut written = Vec::new();
+    if let Some(years) = years {
+        write(&year_path, "pub mod days;")?;
+        write(&years_path, &years)?;
+        written.extend([year_path, years_path]);
+    }
+    write(&module_path, &template())?;
+    write(&days_path, &days)?;
+    written.extend([module_path, days_path]);
+
+    // An input that was already fetched is left alone
+    let input_path = runner::input_path(inputs, year, day, &Source::Input);
+    if !input_path.exists() {
+        write(&input_path, "")?;
+        written.push(input_path);
+    }
+
+    Ok(written)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{env, process};
+
+    use super::*;
+
+    #[test]
+    fn day_names() {
+        assert_eq!(day_name(1).as_deref(), Some("one"));
+        assert_eq!(day_name(16).as_deref(), Some("sixteen"));
+        assert_eq!(day_name(20).as_deref(), Some("twenty"));
+        assert_eq!(day_name(25).as_deref(), Some("twenty_five"));
+        assert_eq!(day_name(26), None);
+    }
+
+    #[test]
+    fn register_in_order() {
+        assert_eq!(register("crate::days! {}", 3, "three"), Ok(String::from("crate::days! {\n    3 => three,\n}")));
+        assert_eq!(
+            register("crate::days! {\n    1 => one,\n    15 => fifteen,\n}", 2, "two"),
+            Ok(String::from("crate::days! {\n    1 => one,\n    2 => two,\n    15 => fifteen,\n}")),
+        );
+        assert_eq!(register("crate::days! {\n    1 => one,\n}", 1, "one"), Err(String::from("1 is already registered as `one`")));
+    }
+
+    #[test]
+    fn new_day_in_new_year() {
+        let root = env::temp_dir().join(format!("aoc-scaffold-{}", process::id()));
+        let (src, inputs) = (root.join("src"), root.join("inputs"));
+        fs::create_dir_all(src.join("years/y2022")).unwrap();
+        fs::write(src.join("years.rs"), "crate::years! {\n    2022 => y2022,\n}").unwrap();
+
+        let written = new_day(&src, &inputs, 2024, 1).unwrap();
+        assert_eq!(written.len(), 5);
+        assert_eq!(fs::read_to_string(src.join("years.rs")).unwrap(), "crate::years! {\n    2022 => y2022,\n    2024 => y2024,\n}");
+        assert_eq!(fs::read_to_string(src.join("years/y2024/days.rs")).unwrap(), "crate::days! {\n    1 => one,\n}");
+        assert_eq!(fs::read_to_string(inputs.join("2024/1.txt")).unwrap(), "");
+
+        // A second day only touches its own year
+        new_day(&src, &inputs, 2024, 2).unwrap();
+        assert_eq!(fs::read_to_string(src.join("years/y2024/days.rs")).unwrap(), "crate::days! {\n    1 => one,\n    2 => two,\n}");
+
+        let e = new_day(&src, &inputs, 2024, 1).unwrap_err();
+        assert!(e.contains("already exists"), "{}", e);
+
+        fs::remove_dir_all(root).unwrap();
+    }
+}
\ No newline at end of file
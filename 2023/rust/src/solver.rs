@@ -0,0 +1,92 @@
+use std::{cell::Cell, fmt::{Debug, Display}, panic, time::Instant};
+
+use crate::error::InputError;
+
+/// The result of solving one part of a day's puzzle.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Answer {
+    Int(i64),
+    Text(String),
+    /// Multi-line output that has to be read by eye, such as a rendered screen.
+    Art(Vec<String>),
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Answer::Int(i) => write!(f, "{}", i),
+            Answer::Text(s) => write!(f, "{}", s),
+            Answer::Art(lines) => write!(f, "{}", lines.join("\n")),
+        }
+    }
+}
+
+impl From<i32> for Answer {
+    fn from(value: i32) -> Self {
+        Answer::Int(value.into())
+    }
+}
+
+impl From<u32> for Answer {
+    fn from(value: u32) -> Self {
+        Answer::Int(value.into())
+    }
+}
+
+impl From<i64> for Answer {
+    fn from(value: i64) -> Self {
+        Answer::Int(value)
+    }
+}
+
+impl From<u64> for Answer {
+    fn from(value: u64) -> Self {
+        Answer::Int(i64::try_from(value).expect("answer does not fit in an i64"))
+    }
+}
+
+impl From<usize> for Answer {
+    fn from(value: usize) -> Self {
+        Answer::Int(i64::try_from(value).expect("answer does not fit in an i64"))
+    }
+}
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Answer::Text(value)
+    }
+}
+
+/// A day's puzzle. The input is parsed once into the day's own `Input` type,
+/// which both parts then borrow. Each part returns its answer rather than
+/// reporting it, so the caller decides what to do with it. Malformed input is
+/// reported as an error instead of a panic.
+pub trait Solver {
+    type Input: Debug + 'static;
+
+    fn parse(input: &str) -> Result<Self::Input, InputError>;
+    fn solve_part_1(input: &Self::Input) -> Result<Answer, InputError>;
+    fn solve_part_2(input: &Self::Input) -> Result<Answer, InputError>;
+}
+
+thread_local! {
+    /// When the part running on this thread has to stop by, if it has a limit.
+    static DEADLINE: Cell<Option<Instant>> = const { Cell::new(None) };
+}
+
+/// What a solver unwinds with when [`checkpoint`] finds it out of time.
+pub struct Cancelled;
+
+/// Gives up on the running part if it has gone past its time limit. Long
+/// simulation loops call this so a broken change can't hang the whole run.
+pub fn checkpoint() {
+    if DEADLINE.with(Cell::get).is_some_and(|deadline| Instant::now() >= deadline) {
+        // Resuming rather than panicking skips the panic hook, as this isn't a bug
+        panic::resume_unwind(Box::new(Cancelled));
+    }
+}
+
+/// Sets the deadline [`checkpoint`] checks against on this thread.
+pub(crate) fn set_deadline(deadline: Option<Instant>) {
+    DEADLINE.with(|d| d.set(deadline));
+}
\ No newline at end of file
@@ -0,0 +1,362 @@
+use std::{fmt::Display, fs, io, path::Path, str::FromStr, time::{SystemTime, UNIX_EPOCH}};
+
+use crate::{answers::{decode, encode}, client::Client, solver::Answer};
+
+/// What the puzzle site made of a submitted answer.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    TooHigh,
+    TooLow,
+    /// Wrong, without saying which way.
+    Wrong,
+    /// Not judged because an answer was given too recently.
+    Throttled,
+    /// Not judged because the part has already been solved.
+    Solved,
+    /// The response didn't match anything known.
+    Unknown,
+}
+
+impl Verdict {
+    /// Whether the answer is known to be wrong, and so never worth sending again.
+    pub fn is_wrong(&self) -> bool {
+        matches!(self, Verdict::TooHigh | Verdict::TooLow | Verdict::Wrong)
+    }
+
+    fn code(&self) -> &'static str {
+        match self {
+            Verdict::Correct => "correct",
+            Verdict::TooHigh => "high",
+            Verdict::TooLow => "low",
+            Verdict::Wrong => "wrong",
+            Verdict::Throttled => "throttled",
+            Verdict::Solved => "solved",
+            Verdict::Unknown => "unknown",
+        }
+    }
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Verdict::Correct => write!(f, "correct"),
+            Verdict::TooHigh => write!(f, "too high"),
+            Verdict::TooLow => write!(f, "too low"),
+            Verdict::Wrong => write!(f, "wrong"),
+            Verdict::Throttled => write!(f, "answered too recently"),
+            Verdict::Solved => write!(f, "already solved"),
+            Verdict::Unknown => write!(f, "unrecognised response"),
+        }
+    }
+}
+
+impl FromStr for Verdict {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "correct" => Ok(Verdict::Correct),
+            "high" => Ok(Verdict::TooHigh),
+            "low" => Ok(Verdict::TooLow),
+            "wrong" => Ok(Verdict::Wrong),
+            "throttled" => Ok(Verdict::Throttled),
+            "solved" => Ok(Verdict::Solved),
+            "unknown" => Ok(Verdict::Unknown),
+            _ => Err(format!("unknown verdict `{}`", s)),
+        }
+    }
+}
+
+/// A parsed response to a submission: the verdict, and how many seconds to
+/// wait before submitting again.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Outcome {
+    pub verdict: Verdict,
+    pub wait: u64,
+}
+
+impl Outcome {
+    pub fn parse(body: &str) -> Self {
+        let verdict = if body.contains("That's the right answer") {
+            Verdict::Correct
+        } else if body.contains("answer is too high") {
+            Verdict::TooHigh
+        } else if body.contains("answer is too low") {
+            Verdict::TooLow
+        } else if body.contains("That's not the right answer") {
+            Verdict::Wrong
+        } else if body.contains("You gave an answer too recently") {
+            Verdict::Throttled
+        } else if body.contains("You don't seem to be solving the right level") {
+            Verdict::Solved
+        } else {
+            Verdict::Unknown
+        };
+
+        Outcome{verdict, wait: wait_seconds(body)}
+    }
+}
+
+/// Picks the wait out of phrases like "You have 4m 48s left to wait" and
+/// "Please wait one minute before trying again".
+fn wait_seconds(body: &str) -> u64 {
+    if let Some((_, rest)) = body.split_once("You have ") {
+        if let Some((time, _)) = rest.split_once(" left to wait") {
+            return time.split_whitespace().map(|t| {
+                let (n, unit) = t.split_at(t.len().saturating_sub(1));
+                let n: u64 = n.parse().unwrap_or(0);
+                match unit {
+                    "h" => n * 3600,
+                    "m" => n * 60,
+                    _ => n,
+                }
+            }).sum();
+        }
+    }
+
+    if let Some((_, rest)) = body.split_once("lease wait ") {
+        let mut words = rest.split_whitespace();
+        let n = match words.next() {
+            Some("one") => 1,
+            Some(n) => n.parse().unwrap_or(0),
+            None => 0,
+        };
+        return match words.next() {
+            Some(unit) if unit.starts_with("minute") => n * 60,
+            _ => n,
+        };
+    }
+
+    0
+}
+
+/// Posts an answer for one part and parses the response.
+pub fn submit(client: &Client, year: u32, day: u32, part: u32, answer: &Answer) -> Result<Outcome, String> {
+    let answer = match answer {
+        Answer::Art(_) => return Err(String::from("the answer is drawn and has to be read by eye before it can be submitted")),
+        answer => answer.to_string(),
+    };
+
+    let response = client.post(&format!("/{}/day/{}/answer", year, day), &[("level", &part.to_string()), ("answer", &answer)])?;
+    match response.status {
+        200 => Ok(Outcome::parse(&response.body)),
+        status => Err(format!("{} returned HTTP {}", client.url(""), status)),
+    }
+}
+
+pub struct Guess {
+    pub year: u32,
+    pub day: u32,
+    pub part: u32,
+    /// When the guess was made, in seconds since the epoch.
+    pub time: u64,
+    pub verdict: Verdict,
+    pub wait: u64,
+    pub answer: String,
+}
+
+/// Every answer submitted, stored one per line as
+/// `<year> <day> <part> <time> <verdict> <wait> <answer>`.
+#[derive(Default)]
+pub struct Guesses {
+    guesses: Vec<Guess>,
+}
+
+/// Whether an answer should be submitted, given the guesses made so far.
+#[derive(Debug, PartialEq, Eq)]
+pub enum Precheck {
+    Ready,
+    /// Seconds left before the site will accept another answer.
+    Throttled(u64),
+    /// The part was already solved with this answer.
+    AlreadyCorrect(String),
+    /// This exact answer was already rejected.
+    KnownWrong(Verdict),
+    /// The answer can't be right given earlier too low and too high guesses.
+    OutOfBounds{low: Option<i64>, high: Option<i64>},
+}
+
+impl Guesses {
+    /// Loads the guess history, treating a missing file as having no guesses.
+    pub fn load(path: &Path) -> Result<Self, String> {
+        match fs::read_to_string(path) {
+            Ok(s) => s.parse().map_err(|e: ParseGuessesError| format!("{}: {}", path.display(), e.reason)),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Guesses::default()),
+            Err(e) => Err(format!("{}: {}", path.display(), e)),
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), String> {
+        fs::write(path, self.to_string()).map_err(|e| format!("{}: {}", path.display(), e))
+    }
+
+    pub fn record(&mut self, year: u32, day: u32, part: u32, answer: &Answer, outcome: &Outcome, time: u64) {
+        self.guesses.push(Guess{year, day, part, time, verdict: outcome.verdict, wait: outcome.wait, answer: encode(answer)});
+    }
+
+    pub fn precheck(&self, year: u32, day: u32, part: u32, answer: &Answer, now: u64) -> Precheck {
+        // The site throttles the whole account, not just the part being guessed
+        let until = self.guesses.iter().map(|g| g.time + g.wait).max().unwrap_or(0);
+        if until > now {
+            return Precheck::Throttled(until - now);
+        }
+
+        let answer = encode(answer);
+        let guesses: Vec<&Guess> = self.guesses.iter().filter(|g| (g.year, g.day, g.part) == (year, day, part)).collect();
+
+        if let Some(correct) = guesses.iter().find(|g| g.verdict == Verdict::Correct) {
+            return Precheck::AlreadyCorrect(decode(&correct.answer));
+        }
+
+        if let Some(wrong) = guesses.iter().find(|g| g.answer == answer && g.verdict.is_wrong()) {
+            return Precheck::KnownWrong(wrong.verdict);
+        }
+
+        let bound = |verdict| guesses.iter().filter(move |g| g.verdict == verdict).filter_map(|g| g.answer.parse::<i64>().ok());
+        let (low, high) = (bound(Verdict::TooLow).max(), bound(Verdict::TooHigh).min());
+        if let Ok(n) = answer.parse::<i64>() {
+            if low.is_some_and(|low| n <= low) || high.is_some_and(|high| n >= high) {
+                return Precheck::OutOfBounds{low, high};
+            }
+        }
+
+        Precheck::Ready
+    }
+}
+
+/// Seconds since the epoch, as guesses are timed.
+pub fn now() -> u64 {
+    SystemTime::now().duration_since(UNIX_EPOCH).map(|d| d.as_secs()).unwrap_or(0)
+}
+
+pub struct ParseGuessesError {
+    pub reason: String,
+}
+
+impl FromStr for Guesses {
+    type Err = ParseGuessesError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut guesses = Vec::new();
+
+        for (i, line) in s.lines().enumerate() {
+            if line.is_empty() || line.starts_with('#') {
+                continue
+            }
+
+            let fields: Vec<&str> = line.splitn(7, ' ').collect();
+            if fields.len() != 7 {
+                return Err(ParseGuessesError{reason: format!("line {}: expected `<year> <day> <part> <time> <verdict> <wait> <answer>`", i+1)});
+            }
+
+            let number = |field: &str| field.parse::<u64>().map_err(|e| ParseGuessesError{reason: format!("line {}: `{}`: {}", i+1, field, e)});
+            let verdict = fields[4].parse().map_err(|e| ParseGuessesError{reason: format!("line {}: {}", i+1, e)})?;
+
+            guesses.push(Guess{
+                year: number(fields[0])? as u32,
+                day: number(fields[1])? as u32,
+                part: number(fields[2])? as u32,
+                time: number(fields[3])?,
+                verdict,
+                wait: number(fields[5])?,
+                answer: fields[6].to_string(),
+            });
+        }
+
+        Ok(Guesses{guesses})
+    }
+}
+
+impl Display for Guesses {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for g in &self.guesses {
+            writeln!(f, "{} {} {} {} {} {} {}", g.year, g.day, g.part, g.time, g.verdict.code(), g.wait, g.answer)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::client::tests::{client, require_curl, stub_server};
+
+    use super::*;
+
+    fn outcome(verdict: Verdict, wait: u64) -> Outcome {
+        Outcome{verdict, wait}
+    }
+
+    #[test]
+    fn parse_responses() {
+        assert_eq!(Outcome::parse("<p>That's the right answer! You are one gold star closer.</p>"), outcome(Verdict::Correct, 0));
+        assert_eq!(
+            Outcome::parse("<p>That's not the right answer; your answer is too high. Please wait one minute before trying again.</p>"),
+            outcome(Verdict::TooHigh, 60),
+        );
+        assert_eq!(
+            Outcome::parse("<p>That's not the right answer; your answer is too low. Please wait 5 minutes before trying again.</p>"),
+            outcome(Verdict::TooLow, 300),
+        );
+        assert_eq!(
+            Outcome::parse("<p>You gave an answer too recently; you have to wait after submitting an answer before trying again. You have 4m 48s left to wait.</p>"),
+            outcome(Verdict::Throttled, 288),
+        );
+        assert_eq!(Outcome::parse("<p>You don't seem to be solving the right level.  Did you already complete it?</p>"), outcome(Verdict::Solved, 0));
+        assert_eq!(Outcome::parse("<p>Something new</p>"), outcome(Verdict::Unknown, 0));
+    }
+
+    #[test]
+    fn precheck_guesses() {
+        let mut guesses = Guesses::default();
+        let answer = |n: i64| Answer::Int(n);
+
+        guesses.record(2022, 1, 1, &answer(500), &outcome(Verdict::TooHigh, 60), 1000);
+        assert_eq!(guesses.precheck(2022, 1, 1, &answer(400), 1030), Precheck::Throttled(30));
+
+        guesses.record(2022, 1, 1, &answer(100), &outcome(Verdict::TooLow, 60), 1100);
+        assert_eq!(guesses.precheck(2022, 1, 1, &answer(500), 1200), Precheck::KnownWrong(Verdict::TooHigh));
+        assert_eq!(guesses.precheck(2022, 1, 1, &answer(600), 1200), Precheck::OutOfBounds{low: Some(100), high: Some(500)});
+        assert_eq!(guesses.precheck(2022, 1, 1, &answer(50), 1200), Precheck::OutOfBounds{low: Some(100), high: Some(500)});
+        assert_eq!(guesses.precheck(2022, 1, 1, &answer(300), 1200), Precheck::Ready);
+        // Bounds only apply to the part they were learned on
+        assert_eq!(guesses.precheck(2022, 1, 2, &answer(600), 1200), Precheck::Ready);
+
+        guesses.record(2022, 1, 1, &answer(300), &outcome(Verdict::Correct, 0), 1200);
+        assert_eq!(guesses.precheck(2022, 1, 1, &answer(301), 1300), Precheck::AlreadyCorrect(String::from("300")));
+    }
+
+    #[test]
+    fn guesses_round_trip() {
+        let mut guesses = Guesses::default();
+        guesses.record(2022, 5, 1, &Answer::Text(String::from("CMZ")), &outcome(Verdict::Wrong, 60), 1000);
+        guesses.record(2022, 5, 2, &Answer::Text(String::from("has spaces")), &outcome(Verdict::Correct, 0), 2000);
+
+        let text = guesses.to_string();
+        assert_eq!(text, "2022 5 1 1000 wrong 60 CMZ\n2022 5 2 2000 correct 0 has spaces\n");
+
+        let parsed: Guesses = text.parse().ok().unwrap();
+        assert_eq!(parsed.to_string(), text);
+        assert!("2022 5 1 1000 maybe 0 CMZ".parse::<Guesses>().is_err());
+    }
+
+    #[test]
+    fn submit_to_stub() {
+        require_curl();
+        let (base_url, requests) = stub_server("200 OK", "<article><p>That's not the right answer; your answer is too low. Please wait one minute before trying again.</p></article>");
+
+        let outcome = submit(&client(base_url), 2022, 1, 2, &Answer::Int(42)).ok().unwrap();
+        assert_eq!(outcome, Outcome{verdict: Verdict::TooLow, wait: 60});
+
+        let request = requests.recv().unwrap();
+        assert!(request.starts_with("POST /2022/day/1/answer "), "{}", request);
+        assert!(request.ends_with("level=2&answer=42"), "{}", request);
+    }
+
+    #[test]
+    fn art_is_not_submitted() {
+        let client = client(String::from("http://127.0.0.1:9"));
+        assert!(submit(&client, 2022, 10, 2, &Answer::Art(vec![String::from("#..#")])).is_err());
+    }
+}
@@ -0,0 +1,4 @@
+crate::years! {
+    2022 => y2022,
+    2023 => y2023,
+}
\ No newline at end of file
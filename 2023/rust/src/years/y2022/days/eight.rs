@@ -0,0 +1,248 @@
+use log::debug;
+
+use crate::{error::InputError, solver::{Answer, Solver}};
+
+pub struct Solution;
+
+impl Solver for Solution {
+    type Input = Vec<Vec<u32>>;
+
+    fn parse(input: &str) -> Result<Self::Input, InputError> {
+        parse_grid(input)
+    }
+
+    fn solve_part_1(trees: &Self::Input) -> Result<Answer, InputError> {
+        Ok(visible_trees(trees).into())
+    }
+
+    fn solve_part_2(trees: &Self::Input) -> Result<Answer, InputError> {
+        Ok(max_scenic_score(trees).into())
+    }
+}
+
+fn parse_grid(input: &str) -> Result<Vec<Vec<u32>>, InputError> {
+    let mut grid = Vec::new();
+
+    for line in input.lines() {
+        let mut row = Vec::new();
+
+        for (i, c) in line.char_indices() {
+            match c.to_digit(10) {
+                Some(d) => row.push(d),
+                None => return Err(InputError::new("tree height is not a digit", &line[i..i+c.len_utf8()])),
+            }
+        }
+
+        // The visibility checks assume a square grid
+        match grid.first().map(Vec::len) {
+            Some(width) if row.len() != width => return Err(InputError::new(format!("row is {} trees wide, expected {}", row.len(), width), line)),
+            Some(width) if grid.len() == width => return Err(InputError::new(format!("grid has more rows than its width of {}", width), line)),
+            _ => (),
+        }
+
+        grid.push(row);
+    }
+
+    match grid.first().map(Vec::len) {
+        Some(width) if grid.len() < width => Err(InputError::unlocated(format!("grid has {} rows, expected {} to match its width", grid.len(), width))),
+        _ => Ok(grid),
+    }
+}
+
+fn visible_trees(grid: &[Vec<u32>]) -> u32 {
+    let mut count = 0;
+
+    for (i, row) in grid.iter().enumerate() {
+        for (j, height) in row.iter().enumerate() {
+            if tree_is_visible(height, i, j, grid) {
+                count += 1;
+            }
+        }
+    }
+
+    count
+}
+
+fn tree_is_visible(height: &u32, x: usize, y: usize, grid: &[Vec<u32>]) -> bool {
+    // borders
+    let grid_size = grid.len()-1;
+    if x.is_multiple_of(grid_size) || y.is_multiple_of(grid_size) {
+        return true
+    }
+
+    // north
+    let mut visible_from_north = true;
+    for row in grid[..x].iter().rev() {
+        if row[y] >= *height {
+            visible_from_north = false;
+            break;
+        }
+    }
+    if visible_from_north {
+        return true
+    }
+
+    // south
+    let mut visible_from_south = true;
+    for row in &grid[x+1..] {
+        if row[y] >= *height {
+            visible_from_south = false;
+            break;
+        }
+    }
+    if visible_from_south {
+        return true
+    }
+
+    // east
+    let mut visible_from_east = true;
+    for tree in grid[x][..y].iter().rev() {
+        if *tree >= *height {
+            visible_from_east = false;
+            break;
+        }
+    }
+    if visible_from_east {
+        return true
+    }
+
+    // west
+    let mut visible_from_west = true;
+    for tree in &grid[x][y+1..] {
+        if *tree >= *height {
+            visible_from_west = false;
+            break;
+        }
+    }
+    if visible_from_west {
+        return true
+    }
+
+    false
+}
+
+fn max_scenic_score(grid: &[Vec<u32>]) -> u32 {
+    let mut max = 0;
+
+    let grid_size = grid.len()-1;
+
+    for (i, row) in grid.iter().enumerate() {
+        if i == 0 || i == grid_size {
+            continue;
+        }
+
+        for (j, height) in row.iter().enumerate() {
+            if j == 0 || j == grid_size {
+                continue;
+            }
+
+            let score = scenic_score(height, i, j, grid);
+            if score > max {
+                max = score;
+            }
+        }
+    }
+
+    max
+}
+
+fn scenic_score(height: &u32, x: usize, y: usize, grid: &[Vec<u32>]) -> u32 {
+    let mut score = 1;
+
+    // north
+    let mut visible_from_north = 0;
+    for row in grid[..x].iter().rev() {
+        visible_from_north += 1;
+        if row[y] >= *height {
+            break;
+        }
+    }
+    debug!("North[{}][{}]: {}", x, y, visible_from_north);
+    score *= visible_from_north;
+
+    // south
+    let mut visible_from_south = 0;
+    for row in &grid[x+1..] {
+        visible_from_south += 1;
+        if row[y] >= *height {
+            break;
+        }
+    }
+    debug!("South[{}][{}]: {}", x, y, visible_from_south);
+    score *= visible_from_south;
+
+    // east
+    let mut visible_from_east = 0;
+    for tree in grid[x][..y].iter().rev() {
+        visible_from_east += 1;
+        if *tree >= *height {
+            break;
+        }
+    }
+    debug!("East[{}][{}]: {}", x, y, visible_from_east);
+    score *= visible_from_east;
+
+    // west
+    let mut visible_from_west = 0;
+    for tree in &grid[x][y+1..] {
+        visible_from_west += 1;
+        if *tree >= *height {
+            break;
+        }
+    }
+    debug!("West[{}][{}]: {}", x, y, visible_from_west);
+    score *= visible_from_west;
+
+    debug!("Score[{}][{}]: {}", x, y, score);
+    score
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "30373
+25512
+65332
+33549
+35390";
+
+    #[test]
+    fn example_part_1() {
+        let input = Solution::parse(EXAMPLE).unwrap();
+        assert_eq!(Solution::solve_part_1(&input), Ok(Answer::Int(21)));
+    }
+
+    #[test]
+    fn example_part_2() {
+        let input = Solution::parse(EXAMPLE).unwrap();
+        assert_eq!(Solution::solve_part_2(&input), Ok(Answer::Int(8)));
+    }
+
+    #[test]
+    fn scenic_scores() {
+        let grid = parse_grid(EXAMPLE).unwrap();
+        assert_eq!(scenic_score(&grid[1][2], 1, 2, &grid), 4);
+        assert_eq!(scenic_score(&grid[3][2], 3, 2, &grid), 8);
+    }
+
+    #[test]
+    fn bad_height() {
+        let input = "303\n2x5\n653";
+        let e = Solution::parse(input).unwrap_err().locate(input);
+        assert_eq!((e.line, e.column, e.text.as_str()), (Some(2), Some(2), "x"));
+    }
+    #[test]
+    fn ragged_grid() {
+        let input = "123\n45\n789";
+        let e = Solution::parse(input).unwrap_err().locate(input);
+        assert_eq!(e.to_string(), "line 2, column 1: row is 2 trees wide, expected 3: `45`");
+
+        let input = "12\n45\n78";
+        let e = Solution::parse(input).unwrap_err().locate(input);
+        assert_eq!(e.to_string(), "line 3, column 1: grid has more rows than its width of 2: `78`");
+
+        let e = Solution::parse("123\n456").unwrap_err();
+        assert_eq!(e.to_string(), "input: grid has 2 rows, expected 3 to match its width");
+    }
+}
\ No newline at end of file
@@ -0,0 +1,341 @@
+use std::{str::FromStr, collections::VecDeque};
+
+use log::debug;
+
+use crate::{error::{self, InputError}, solver::{self, Answer, Solver}};
+
+pub struct Solution;
+
+impl Solver for Solution {
+    type Input = Vec<Monkey>;
+
+    fn parse(input: &str) -> Result<Self::Input, InputError> {
+        parse_monkeys(input)
+    }
+
+    fn solve_part_1(monkeys: &Self::Input) -> Result<Answer, InputError> {
+        let mut monkeys = monkeys.clone();
+
+        for _ in 0..20 {
+            for monkey in 0..monkeys.len() {
+                while let Some((to, item)) = monkeys[monkey].process_divisor() {
+                    solver::checkpoint();
+                    monkeys[to as usize].catch(item);
+                }
+            }
+        }
+
+        monkeys.sort();
+        let monkey_business: u64 = monkeys.iter().map(|m| m.inspections).rev().take(2).product();
+        Ok(monkey_business.into())
+    }
+
+    fn solve_part_2(monkeys: &Self::Input) -> Result<Answer, InputError> {
+        let mut monkeys = monkeys.clone();
+
+        let divisor: u64 = monkeys.iter().map(|m| m.factor).product();
+
+        for round in 0..10000 {
+            debug!("round: {}", round);
+            for monkey in 0..monkeys.len() {
+                while let Some((to, item)) = monkeys[monkey].process_modulo(divisor) {
+                    solver::checkpoint();
+                    monkeys[to as usize].catch(item);
+                }
+            }
+        }
+
+        monkeys.sort();
+        for monkey in &monkeys {
+            debug!("{}, inspections: {}", monkey.number, monkey.inspections);
+        }
+        let monkey_business: u64 = monkeys.iter().map(|m| m.inspections).rev().take(2).product();
+        Ok(monkey_business.into())
+    }
+}
+
+fn parse_monkeys(input: &str) -> Result<Vec<Monkey>, InputError> {
+    let mut vec = Vec::new();
+    let mut all_notes = Vec::new();
+
+    let monkey_notes = input.split("\n\n");
+    for notes in monkey_notes {
+        if notes.trim().is_empty() {
+            continue
+        }
+
+        vec.push(notes.parse::<Monkey>()?);
+        all_notes.push(notes);
+    }
+
+    for (index, (monkey, notes)) in vec.iter().zip(all_notes).enumerate() {
+        for (branch, target) in [("If true:", monkey.factor_true), ("If false:", monkey.factor_false)] {
+            // Point at the target's number on the line that throws to it
+            let text = notes.lines()
+                .find(|line| line.trim_start().starts_with(branch))
+                .and_then(|line| line.rsplit(' ').next())
+                .unwrap_or(notes);
+
+            if target as usize >= vec.len() {
+                return Err(InputError::new(format!("monkey {} throws to missing monkey {}", monkey.number, target), text));
+            }
+            // It would throw the same items to itself forever
+            if target as usize == index {
+                return Err(InputError::new(format!("monkey {} throws to itself", monkey.number), text));
+            }
+        }
+    }
+
+    Ok(vec)
+}
+
+#[derive(Clone, Debug)]
+pub struct Monkey {
+    number: u64,
+    items: VecDeque<u64>,
+    operation: Operation,
+    factor: u64,
+    factor_true: u64,
+    factor_false: u64,
+
+    inspections: u64,
+}
+
+impl Monkey {
+    fn process_divisor(&mut self) -> Option<(u64, u64)> {
+        match self.items.pop_front() {
+            Some(mut item) => {
+                self.inspections += 1;
+
+                item = match self.operation {
+                    Operation::AdditionSelf => item.saturating_add(item),
+                    Operation::Addition(val) => item.saturating_add(val),
+                    Operation::MultiplicationSelf => (item).saturating_mul(item),
+                    Operation::Multiplication(val) => (item).saturating_mul(val),
+                };
+
+                item /= 3;
+
+                let m = if item % self.factor == 0 {
+                    self.factor_true
+                } else {
+                    self.factor_false
+                };
+
+                Some((m, item))
+            }
+            None => None,
+        }
+    }
+
+    fn process_modulo(&mut self, modulo: u64) -> Option<(u64, u64)> {
+        match self.items.pop_front() {
+            Some(mut item) => {
+                self.inspections += 1;
+
+                item = match self.operation {
+                    Operation::AdditionSelf => item.saturating_add(item),
+                    Operation::Addition(val) => item.saturating_add(val),
+                    Operation::MultiplicationSelf => (item%modulo).saturating_mul(item%modulo),
+                    Operation::Multiplication(val) => (item%modulo).saturating_mul(val%modulo),
+                };
+
+                let m = if item % self.factor == 0 {
+                    self.factor_true
+                } else {
+                    self.factor_false
+                };
+
+                Some((m, item))
+            }
+            None => None,
+        }
+    }
+
+    fn catch(&mut self, item: u64) {
+        self.items.push_back(item)
+    }
+}
+
+impl PartialEq for Monkey {
+    fn eq(&self, other: &Self) -> bool {
+        self.inspections == other.inspections
+    }
+}
+
+impl PartialOrd for Monkey {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Eq for Monkey {}
+
+impl Ord for Monkey {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.inspections.cmp(&other.inspections)
+    }
+}
+
+impl FromStr for Monkey {
+    type Err = InputError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let lines: Vec<&str> = s.lines().collect();
+
+        if lines.len() != 6 {
+            return Err(InputError::new(format!("expected 6 lines of notes, found {}", lines.len()), s));
+        }
+
+        let number = error::parse(field(lines[0], "Monkey ", ":")?)?;
+
+        let mut items = VecDeque::new();
+        for num in field(lines[1], "  Starting items: ", "")?.split(", ") {
+            items.push_back(error::parse(num)?);
+        }
+
+        let operation = lines[2].parse::<Operation>()?;
+        let factor_text = field(lines[3], "  Test: divisible by ", "")?;
+        let factor = match error::parse(factor_text)? {
+            0 => return Err(InputError::new("can't test divisibility by 0", factor_text)),
+            factor => factor,
+        };
+        let factor_true = error::parse(field(lines[4], "    If true: throw to monkey ", "")?)?;
+        let factor_false = error::parse(field(lines[5], "    If false: throw to monkey ", "")?)?;
+
+        Ok(Monkey{
+            number,
+            items,
+            operation,
+            factor,
+            factor_true,
+            factor_false,
+
+            inspections: 0,
+        })
+    }
+}
+
+/// The part of `line` between `prefix` and `suffix`.
+fn field<'a>(line: &'a str, prefix: &str, suffix: &str) -> Result<&'a str, InputError> {
+    match line.strip_prefix(prefix).and_then(|line| line.strip_suffix(suffix)) {
+        Some(value) => Ok(value),
+        None => Err(InputError::new(format!("expected `{}...{}`", prefix.trim_start(), suffix), line)),
+    }
+}
+
+#[derive(Clone, Debug)]
+enum Operation {
+    AdditionSelf,
+    Addition(u64),
+    MultiplicationSelf,
+    Multiplication(u64),
+}
+
+impl FromStr for Operation {
+    type Err = InputError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (operator, value) = match field(s, "  Operation: new = old ", "")?.split_once(" ") {
+            Some(split) => split,
+            None => return Err(InputError::new("expected `<operator> <value>`", s)),
+        };
+
+        match (operator, value) {
+            ("+", "old") => Ok(Operation::AdditionSelf),
+            ("*", "old") => Ok(Operation::MultiplicationSelf),
+            ("+", value) => Ok(Operation::Addition(error::parse(value)?)),
+            ("*", value) => Ok(Operation::Multiplication(error::parse(value)?)),
+            (operator, _) => Err(InputError::new("unknown operator", operator)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "Monkey 0:
+  Starting items: 79, 98
+  Operation: new = old * 19
+  Test: divisible by 23
+    If true: throw to monkey 2
+    If false: throw to monkey 3
+
+Monkey 1:
+  Starting items: 54, 65, 75, 74
+  Operation: new = old + 6
+  Test: divisible by 19
+    If true: throw to monkey 2
+    If false: throw to monkey 0
+
+Monkey 2:
+  Starting items: 79, 60, 97
+  Operation: new = old * old
+  Test: divisible by 13
+    If true: throw to monkey 1
+    If false: throw to monkey 3
+
+Monkey 3:
+  Starting items: 74
+  Operation: new = old + 3
+  Test: divisible by 17
+    If true: throw to monkey 0
+    If false: throw to monkey 1";
+
+    #[test]
+    fn example_part_1() {
+        let input = Solution::parse(EXAMPLE).unwrap();
+        assert_eq!(Solution::solve_part_1(&input), Ok(Answer::Int(10605)));
+    }
+
+    #[test]
+    fn example_part_2() {
+        let input = Solution::parse(EXAMPLE).unwrap();
+        assert_eq!(Solution::solve_part_2(&input), Ok(Answer::Int(2713310158)));
+    }
+
+    #[test]
+    fn parse_monkey() {
+        let monkeys = parse_monkeys(EXAMPLE).unwrap();
+        assert_eq!(monkeys.len(), 4);
+
+        let monkey = &monkeys[2];
+        assert_eq!(monkey.number, 2);
+        assert_eq!(monkey.items, VecDeque::from(vec![79, 60, 97]));
+        assert!(matches!(monkey.operation, Operation::MultiplicationSelf));
+        assert_eq!((monkey.factor, monkey.factor_true, monkey.factor_false), (13, 1, 3));
+
+        assert!(matches!("  Operation: new = old + 6".parse(), Ok(Operation::Addition(6))));
+        assert!("  Operation: new = old - 6".parse::<Operation>().is_err());
+        assert!("Monkey 0:\n  Starting items: 79".parse::<Monkey>().is_err());
+
+        let input = EXAMPLE.replace("79, 60, 97", "79, sixty, 97");
+        let e = parse_monkeys(&input).err().unwrap().locate(&input);
+        assert_eq!((e.line, e.column, e.text.as_str()), (Some(16), Some(23), "sixty"));
+
+        let input = EXAMPLE.replacen("divisible by 19", "divisible by 0", 1);
+        let e = parse_monkeys(&input).err().unwrap().locate(&input);
+        assert_eq!(e.to_string(), "line 11, column 22: can't test divisibility by 0: `0`");
+
+        let input = EXAMPLE.replacen("throw to monkey 0", "throw to monkey 1", 1);
+        let e = parse_monkeys(&input).err().unwrap().locate(&input);
+        assert_eq!(e.to_string(), "line 13, column 31: monkey 1 throws to itself: `1`");
+
+        let input = EXAMPLE.replacen("throw to monkey 3", "throw to monkey 4", 1);
+        let e = parse_monkeys(&input).err().unwrap().locate(&input);
+        assert_eq!(e.to_string(), "line 6, column 31: monkey 0 throws to missing monkey 4: `4`");
+    }
+
+    #[test]
+    fn monkey_throws() {
+        let mut monkey = parse_monkeys(EXAMPLE).unwrap().remove(0);
+
+        // 79 * 19 / 3 = 500, not divisible by 23
+        assert_eq!(monkey.process_divisor(), Some((3, 500)));
+        // 98 * 19 / 3 = 620, not divisible by 23
+        assert_eq!(monkey.process_divisor(), Some((3, 620)));
+        assert_eq!(monkey.process_divisor(), None);
+        assert_eq!(monkey.inspections, 2);
+    }
+}
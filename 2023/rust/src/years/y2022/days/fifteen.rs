@@ -0,0 +1,240 @@
+use std::{str::FromStr, fmt::Debug, cmp, collections::HashSet};
+
+use log::debug;
+
+use crate::{error::{self, InputError}, solver::{self, Answer, Solver}};
+
+const ROW: i32 = 2_000_000;
+const SEARCH_SPACE: i32 = 4_000_000;
+
+pub struct Solution;
+
+impl Solver for Solution {
+    type Input = Map;
+
+    fn parse(input: &str) -> Result<Self::Input, InputError> {
+        input.parse()
+    }
+
+    fn solve_part_1(map: &Self::Input) -> Result<Answer, InputError> {
+        for sensor in &map.sensors {
+            debug!("{:?}", sensor);
+        }
+
+        Ok(map.beacon_blocked(ROW).into())
+    }
+
+    fn solve_part_2(map: &Self::Input) -> Result<Answer, InputError> {
+        match map.distress_beacon(SEARCH_SPACE) {
+            Some(beacon) => Ok((beacon.x as i64 * 4_000_000 + beacon.y as i64).into()),
+            None => Err(InputError::unlocated("no position left for the distress beacon")),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Map {
+    sensors: Vec<Sensor>,
+}
+
+impl Map {
+    /// Ranges of x covered by at least one sensor on row y, merged and sorted.
+    fn coverage(&self, y: i32) -> Vec<(i32, i32)> {
+        let mut ranges: Vec<(i32, i32)> = self.sensors.iter().filter_map(|s| s.coverage(y)).collect();
+        ranges.sort();
+
+        let mut merged: Vec<(i32, i32)> = Vec::new();
+        for (start, end) in ranges {
+            match merged.last_mut() {
+                Some(last) if start <= last.1 + 1 => last.1 = cmp::max(last.1, end),
+                _ => merged.push((start, end)),
+            }
+        }
+
+        merged
+    }
+
+    fn beacon_blocked(&self, y: i32) -> u32 {
+        let coverage = self.coverage(y);
+
+        let mut blocked: u32 = coverage.iter().map(|(start, end)| start.abs_diff(*end) + 1).sum();
+
+        let beacons: HashSet<i32> = self.sensors.iter()
+            .filter(|s| s.beacon.y == y)
+            .map(|s| s.beacon.x)
+            .collect();
+        for x in beacons {
+            if coverage.iter().any(|(start, end)| *start <= x && x <= *end) {
+                blocked -= 1;
+            }
+        }
+
+        blocked
+    }
+
+    fn distress_beacon(&self, max: i32) -> Option<Point> {
+        for y in 0..=max {
+            solver::checkpoint();
+            let mut x = 0;
+            for (start, end) in self.coverage(y) {
+                if start > x {
+                    break
+                }
+                x = cmp::max(x, end + 1);
+            }
+
+            if x <= max {
+                return Some(Point{x, y});
+            }
+        }
+
+        None
+    }
+}
+
+impl FromStr for Map {
+    type Err = InputError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut sensors = Vec::new();
+
+        for line in s.lines() {
+            let sensor = line.parse()?;
+            sensors.push(sensor);
+        }
+
+        Ok(Map{sensors})
+    }
+}
+
+#[derive(Debug)]
+struct Sensor {
+    location: Point,
+    beacon: Point,
+}
+
+impl Sensor {
+    /// The range of x on row y that is closer to this sensor than its beacon.
+    fn coverage(&self, y: i32) -> Option<(i32, i32)> {
+        let radius = self.location.distance_to(&self.beacon);
+        let spread = radius.checked_sub(self.location.y.abs_diff(y))? as i32;
+
+        Some((self.location.x - spread, self.location.x + spread))
+    }
+}
+
+impl FromStr for Sensor {
+    type Err = InputError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (sensor_str, beacon_str) = match s.split_once(": ") {
+            Some(split) => split,
+            None => return Err(InputError::new("expected `<sensor>: <beacon>`", s)),
+        };
+
+        let sensor_str = strip(sensor_str, "Sensor at ")?;
+        let beacon_str = strip(beacon_str, "closest beacon is at ")?;
+
+        Ok(Sensor{
+            location: sensor_str.parse()?,
+            beacon: beacon_str.parse()?
+        })
+    }
+}
+
+#[derive(Debug)]
+struct Point {
+    x: i32,
+    y: i32,
+}
+
+impl Point {
+    fn distance_to(&self, other: &Self) -> u32 {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+    }
+}
+
+impl FromStr for Point {
+    type Err = InputError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (x_str, y_str) = match s.split_once(", ") {
+            Some(split) => split,
+            None => return Err(InputError::new("expected `x=<x>, y=<y>`", s)),
+        };
+
+        Ok(Point{
+            x: error::parse(strip(x_str, "x=")?)?,
+            y: error::parse(strip(y_str, "y=")?)?,
+        })
+    }
+}
+
+fn strip<'a>(s: &'a str, prefix: &str) -> Result<&'a str, InputError> {
+    match s.strip_prefix(prefix) {
+        Some(s) => Ok(s),
+        None => Err(InputError::new(format!("expected `{}`", prefix), s)),
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "Sensor at x=2, y=18: closest beacon is at x=-2, y=15
+Sensor at x=9, y=16: closest beacon is at x=10, y=16
+Sensor at x=13, y=2: closest beacon is at x=15, y=3
+Sensor at x=12, y=14: closest beacon is at x=10, y=16
+Sensor at x=10, y=20: closest beacon is at x=10, y=16
+Sensor at x=14, y=17: closest beacon is at x=10, y=16
+Sensor at x=8, y=7: closest beacon is at x=2, y=10
+Sensor at x=2, y=0: closest beacon is at x=2, y=10
+Sensor at x=0, y=11: closest beacon is at x=2, y=10
+Sensor at x=20, y=14: closest beacon is at x=25, y=17
+Sensor at x=17, y=20: closest beacon is at x=21, y=22
+Sensor at x=16, y=7: closest beacon is at x=15, y=3
+Sensor at x=14, y=3: closest beacon is at x=15, y=3
+Sensor at x=20, y=1: closest beacon is at x=15, y=3";
+
+    fn map() -> Map {
+        EXAMPLE.parse().unwrap()
+    }
+
+    // The example asks about row 10 and a search space of 20 rather than the
+    // real input's constants, so it is checked below the solver entry points.
+    #[test]
+    fn example_part_1() {
+        assert_eq!(map().beacon_blocked(10), 26);
+    }
+
+    #[test]
+    fn example_part_2() {
+        let beacon = map().distress_beacon(20).expect("beacon found");
+        assert_eq!((beacon.x, beacon.y), (14, 11));
+    }
+
+    #[test]
+    fn parse_sensor() {
+        let sensor: Sensor = "Sensor at x=2, y=18: closest beacon is at x=-2, y=15".parse().unwrap();
+        assert_eq!((sensor.location.x, sensor.location.y), (2, 18));
+        assert_eq!((sensor.beacon.x, sensor.beacon.y), (-2, 15));
+
+        assert!("Sensor at x=2, y=18".parse::<Sensor>().is_err());
+        assert!("Sensor at x=2, y=18: closest beacon is at x=-2".parse::<Sensor>().is_err());
+
+        let input = EXAMPLE.replace("y=14:", "y=1.4:");
+        let e = input.parse::<Map>().err().unwrap().locate(&input);
+        assert_eq!((e.line, e.column, e.text.as_str()), (Some(4), Some(19), "1.4"));
+    }
+
+    #[test]
+    fn sensor_coverage() {
+        let sensor: Sensor = "Sensor at x=8, y=7: closest beacon is at x=2, y=10".parse().unwrap();
+
+        assert_eq!(sensor.coverage(7), Some((-1, 17)));
+        assert_eq!(sensor.coverage(10), Some((2, 14)));
+        assert_eq!(sensor.coverage(16), Some((8, 8)));
+        assert_eq!(sensor.coverage(17), None);
+    }
+}
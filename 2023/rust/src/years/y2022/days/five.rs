@@ -0,0 +1,208 @@
+use crate::{error::{self, InputError}, solver::{Answer, Solver}};
+
+pub struct Solution;
+
+impl Solver for Solution {
+    type Input = Cargo;
+
+    fn parse(input: &str) -> Result<Self::Input, InputError> {
+        parse_input(input)
+    }
+
+    fn solve_part_1(cargo: &Self::Input) -> Result<Answer, InputError> {
+        let mut stacks = cargo.stacks.clone();
+
+        for m in &cargo.moves {
+            for _ in 0..m.count {
+                if let Some(c) = stacks[m.from_stack-1].pop() {
+                    stacks[m.to_stack-1].push(c);
+                }
+            }
+        }
+
+        Ok(top_crates(stacks).into())
+    }
+
+    fn solve_part_2(cargo: &Self::Input) -> Result<Answer, InputError> {
+        let mut stacks = cargo.stacks.clone();
+
+        for m in &cargo.moves {
+            let from = &mut stacks[m.from_stack-1];
+            let mut drained = Vec::from_iter(from.drain(from.len()-m.count..));
+            stacks[m.to_stack-1].append(&mut drained);
+        }
+
+        Ok(top_crates(stacks).into())
+    }
+}
+
+fn top_crates(stacks: Vec<Vec<char>>) -> String {
+    let mut code = String::new();
+    for mut stack in stacks {
+        if let Some(c) = stack.pop() {
+            code.push(c);
+        }
+    }
+
+    code
+}
+
+/// The starting stacks and the moves made from them. Every move is known to
+/// have enough cargo on its stack.
+#[derive(Debug)]
+pub struct Cargo {
+    stacks: Vec<Vec<char>>,
+    moves: Vec<Move>,
+}
+
+fn parse_input(input: &str) -> Result<Cargo, InputError> {
+    let mut lines = input.lines();
+
+    let mut drawing_lines = Vec::new();
+    for line in lines.by_ref() {
+        if line.is_empty() {
+            break
+        }
+
+        drawing_lines.push(line);
+    }
+
+    let mut move_lines = Vec::new();
+    for line in lines {
+        if line.is_empty() {
+            break
+        }
+
+        move_lines.push(line);
+    }
+
+    let stacks = parse_drawing(input, drawing_lines)?;
+    let moves = parse_moves(move_lines, &stacks)?;
+    Ok(Cargo{stacks, moves})
+}
+
+fn parse_drawing(input: &str, mut lines: Vec<&str>) -> Result<Vec<Vec<char>>, InputError> {
+    let mut stacks = new_stacks(match lines.pop() {
+        Some(s) => s,
+        None => return Err(InputError::new("no stack base", input)),
+    });
+
+    while let Some(s) = lines.pop() {
+        add_crates(&mut stacks, s)?;
+    }
+
+    Ok(stacks)
+}
+
+fn new_stacks(input: &str) -> Vec<Vec<char>>{
+    let mut vec = Vec::new();
+
+    for _ in input.split_whitespace() {
+        vec.push(Vec::new());
+    }
+
+    vec
+}
+
+fn add_crates(stacks: &mut [Vec<char>], input: &str) -> Result<(), InputError> {
+    for (i, stack) in stacks.iter_mut().enumerate() {
+        match input.get(i*4..i*4+3) {
+            Some(s) => add_crate(stack, s),
+            // Trailing empty stacks may be trimmed from the line.
+            None if input.len() <= i*4 => break,
+            None => return Err(InputError::new("expected a crate like `[A]`", &input[i*4..])),
+        }
+    }
+
+    Ok(())
+}
+
+fn add_crate(stack: &mut Vec<char>, input: &str) {
+    match input.chars().nth(1) {
+        Some(' ') | None => (),
+        Some(c) => stack.push(c),
+    }
+}
+
+#[derive(Debug)]
+struct Move {
+    count: usize,
+    from_stack: usize,
+    to_stack: usize,
+}
+
+fn parse_moves(lines: Vec<&str>, stacks: &[Vec<char>]) -> Result<Vec<Move>, InputError> {
+    let mut vec = Vec::new();
+    // Track how much cargo each stack holds so impossible moves are caught here
+    let mut heights: Vec<usize> = stacks.iter().map(Vec::len).collect();
+
+    for line in lines {
+        let m = parse_move(line, stacks.len())?;
+        if heights[m.from_stack-1] < m.count {
+            return Err(InputError::new("not enough cargo on stack", line));
+        }
+        heights[m.from_stack-1] -= m.count;
+        heights[m.to_stack-1] += m.count;
+
+        vec.push(m);
+    }
+
+    Ok(vec)
+}
+
+fn parse_move(line: &str, stacks: usize) -> Result<Move, InputError> {
+    let words: Vec<&str> = line.split_whitespace().collect();
+    let (count, from, to) = match words[..] {
+        ["move", count, "from", from, "to", to] => (count, from, to),
+        _ => return Err(InputError::new("expected `move <n> from <stack> to <stack>`", line)),
+    };
+
+    let m = Move{count: error::parse(count)?, from_stack: error::parse(from)?, to_stack: error::parse(to)?};
+    for stack in [m.from_stack, m.to_stack] {
+        if stack == 0 || stack > stacks {
+            return Err(InputError::new(format!("no stack {}", stack), line));
+        }
+    }
+
+    Ok(m)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = concat!(
+        "    [D]    \n",
+        "[N] [C]    \n",
+        "[Z] [M] [P]\n",
+        " 1   2   3 \n",
+        "\n",
+        "move 1 from 2 to 1\n",
+        "move 3 from 1 to 3\n",
+        "move 2 from 2 to 1\n",
+        "move 1 from 1 to 2\n",
+    );
+
+    #[test]
+    fn example_part_1() {
+        let input = Solution::parse(EXAMPLE).unwrap();
+        assert_eq!(Solution::solve_part_1(&input), Ok(Answer::Text(String::from("CMZ"))));
+    }
+
+    #[test]
+    fn example_part_2() {
+        let input = Solution::parse(EXAMPLE).unwrap();
+        assert_eq!(Solution::solve_part_2(&input), Ok(Answer::Text(String::from("MCD"))));
+    }
+
+    #[test]
+    fn bad_moves() {
+        let input = EXAMPLE.replace("move 2 from 2 to 1", "move 2 from 4 to 1");
+        let e = Solution::parse(&input).unwrap_err().locate(&input);
+        assert_eq!((e.line, e.reason.as_str()), (Some(8), "no stack 4"));
+
+        let input = EXAMPLE.replace("move 3 from 1 to 3", "move 4 from 1 to 3");
+        let e = Solution::parse(&input).unwrap_err().locate(&input);
+        assert_eq!((e.line, e.reason.as_str()), (Some(7), "not enough cargo on stack"));
+    }
+}
\ No newline at end of file
@@ -0,0 +1,161 @@
+use std::{str::FromStr, cmp::Ordering};
+
+use crate::{error::{self, InputError}, solver::{Answer, Solver}};
+
+#[derive(Debug)]
+struct Assignment {
+    start: u32,
+    end: u32,
+}
+
+impl FromStr for Assignment {
+    type Err = InputError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (first, second) = match s.split_once("-") {
+            Some(value) => value,
+            None => return Err(InputError::new("expected a `start-end` range", s)),
+        };
+
+        let start: u32 = error::parse(first)?;
+        let end: u32 = error::parse(second)?;
+
+        Ok(Assignment { start, end })
+    }
+}
+
+#[derive(Debug)]
+pub struct AssignmentPair (Assignment, Assignment);
+
+impl AssignmentPair {
+    fn full_overlap(&self) -> bool {
+        let start_cmp = self.0.start.cmp(&self.1.start);
+        let end_cmp = self.0.end.cmp(&self.1.end);
+
+        match (start_cmp, end_cmp) {
+            (Ordering::Less, Ordering::Less) => false,
+            (Ordering::Greater, Ordering::Greater) => false,
+            (_, _) => true,
+        }
+    }
+
+    fn partial_overlap(&self) -> bool {
+        let start_cmp = self.0.start.cmp(&self.1.end);
+        let end_cmp = self.0.end.cmp(&self.1.start);
+
+        match (start_cmp, end_cmp) {
+            (Ordering::Greater, _) => false,
+            (_, Ordering::Less) => false,
+            (_, _) => true,
+        }
+    }
+}
+
+fn detail_assignments(input: &str) -> Result<Vec<AssignmentPair>, InputError> {
+    let mut vec = Vec::new();
+
+    for line in input.lines() {
+        let (first, second) = match line.split_once(",") {
+            Some(value) => value,
+            None => return Err(InputError::new("expected two assignments separated by `,`", line)),
+        };
+
+        vec.push(AssignmentPair(first.parse()?, second.parse()?));
+    }
+
+    Ok(vec)
+}
+
+fn count_fully_contained(pairs: &[AssignmentPair]) -> u32 {
+    let mut count = 0;
+
+    for pair in pairs {
+        if pair.full_overlap() {
+            count += 1;
+        }
+    }
+
+    count
+}
+
+fn count_partially_contained(pairs: &[AssignmentPair]) -> u32 {
+    let mut count = 0;
+
+    for pair in pairs {
+        if pair.partial_overlap() {
+            count += 1;
+        }
+    }
+
+    count
+}
+
+pub struct Solution;
+
+impl Solver for Solution {
+    type Input = Vec<AssignmentPair>;
+
+    fn parse(input: &str) -> Result<Self::Input, InputError> {
+        detail_assignments(input)
+    }
+
+    fn solve_part_1(pairs: &Self::Input) -> Result<Answer, InputError> {
+        Ok(count_fully_contained(pairs).into())
+    }
+
+    fn solve_part_2(pairs: &Self::Input) -> Result<Answer, InputError> {
+        Ok(count_partially_contained(pairs).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "2-4,6-8
+2-3,4-5
+5-7,7-9
+2-8,3-7
+6-6,4-6
+2-6,4-8";
+
+    #[test]
+    fn example_part_1() {
+        let input = Solution::parse(EXAMPLE).unwrap();
+        assert_eq!(Solution::solve_part_1(&input), Ok(Answer::Int(2)));
+    }
+
+    #[test]
+    fn example_part_2() {
+        let input = Solution::parse(EXAMPLE).unwrap();
+        assert_eq!(Solution::solve_part_2(&input), Ok(Answer::Int(4)));
+    }
+
+    #[test]
+    fn parse_assignment() {
+        let assignment: Assignment = "12-34".parse().unwrap();
+        assert_eq!((assignment.start, assignment.end), (12, 34));
+
+        assert!("12".parse::<Assignment>().is_err());
+        assert!("a-4".parse::<Assignment>().is_err());
+
+        let input = "2-4,6-8\n2-3;4-5\n";
+        let e = Solution::parse(input).unwrap_err().locate(input);
+        assert_eq!((e.line, e.column, e.text.as_str()), (Some(2), Some(1), "2-3;4-5"));
+    }
+
+    #[test]
+    fn assignment_overlaps() {
+        let contained = AssignmentPair(Assignment{start: 6, end: 6}, Assignment{start: 4, end: 6});
+        assert!(contained.full_overlap());
+        assert!(contained.partial_overlap());
+
+        let touching = AssignmentPair(Assignment{start: 5, end: 7}, Assignment{start: 7, end: 9});
+        assert!(!touching.full_overlap());
+        assert!(touching.partial_overlap());
+
+        let apart = AssignmentPair(Assignment{start: 2, end: 4}, Assignment{start: 6, end: 8});
+        assert!(!apart.full_overlap());
+        assert!(!apart.partial_overlap());
+    }
+}
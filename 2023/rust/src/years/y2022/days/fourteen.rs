@@ -1,28 +1,30 @@
 use std::{collections::VecDeque, str::FromStr, fmt::{Debug, Write}, cmp};
 
-use log::info;
+use crate::{error::{self, InputError}, solver::{self, Answer, Solver}};
 
-pub fn solve_part_1(input: &str) {
-    let formations = parse_formations(input);
-    let mut cave = Cave::new(formations);
+pub struct Solution;
 
-    let sand = cave.fill_abyss();
+impl Solver for Solution {
+    /// The cave with its rock formations filled in, before any sand falls.
+    type Input = Cave;
 
-    info!("amount of sand: {}", sand);
-}
-
-pub fn solve_part_2(input: &str) {
-    let formations = parse_formations(input);
-    let mut cave = Cave::new(formations);
+    fn parse(input: &str) -> Result<Self::Input, InputError> {
+        Ok(Cave::new(parse_formations(input)?))
+    }
 
-    let sand = cave.fill_floor();
+    fn solve_part_1(cave: &Self::Input) -> Result<Answer, InputError> {
+        let mut cave = cave.clone();
+        Ok(cave.fill_abyss().into())
+    }
 
-    info!("amount of sand: {}", sand);
+    fn solve_part_2(cave: &Self::Input) -> Result<Answer, InputError> {
+        let mut cave = cave.clone();
+        Ok(cave.fill_floor().into())
+    }
 }
 
-struct ParseError;
-
-struct Cave{
+#[derive(Clone)]
+pub struct Cave{
     left: usize,
     right: usize,
     depth: usize,
@@ -57,13 +59,13 @@ impl Cave {
                 let min = cmp::min(start.y, end.y);
                 let max = cmp::max(start.y, end.y);
                 for y in min..=max {
-                    self.fill_rock(Point{x: start.x, y: y});
+                    self.fill_rock(Point{x: start.x, y});
                 }
             } else if start.y == end.y {
                 let min = cmp::min(start.x, end.x);
                 let max = cmp::max(start.x, end.x);
                 for x in min..=max {
-                    self.fill_rock(Point{x: x, y: start.y});
+                    self.fill_rock(Point{x, y: start.y});
                 }
             }
         }
@@ -128,6 +130,7 @@ impl Cave {
             let mut x = 500-self.left;
             let mut y = 0;
             loop {
+                solver::checkpoint();
                 match self.formation.get_mut(y+1) {
                     None => {
                         return total_sand;
@@ -178,6 +181,7 @@ impl Cave {
                 return total_sand;
             }            
             loop {
+                solver::checkpoint();
                 match self.formation.get_mut(y+1) {
                     None => {
                         return total_sand;
@@ -214,17 +218,14 @@ impl Cave {
     }
 }
 
-fn parse_formations(input: &str) -> Vec<Formation> {
+fn parse_formations(input: &str) -> Result<Vec<Formation>, InputError> {
     let mut vec = Vec::new();
 
     for line in input.lines() {
-        match line.parse() {
-            Ok(f) => vec.push(f),
-            Err(_) => continue,
-        }
+        vec.push(line.parse()?);
     }
 
-    vec
+    Ok(vec)
 }
 
 struct Formation {
@@ -232,19 +233,16 @@ struct Formation {
 }
 
 impl FromStr for Formation {
-    type Err = ParseError;
+    type Err = InputError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let mut points = Vec::new();
 
         for point in s.split(" -> ") {
-            match point.parse() {
-                Ok(point) => points.push(point),
-                Err(_) => return Err(ParseError),
-            }
+            points.push(point.parse()?);
         }
 
-        Ok(Formation{points: points})
+        Ok(Formation{points})
     }
 }
 
@@ -254,25 +252,28 @@ struct Point {
 }
 
 impl FromStr for Point {
-    type Err = ParseError;
+    type Err = InputError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let (x, y) = match s.split_once(",") {
             Some(p) => p,
-            None => return Err(ParseError),
-        };
-
-        let x = match x.parse() {
-            Ok(d) => d,
-            Err(_) => return Err(ParseError),
+            None => return Err(InputError::new("expected `<x>,<y>`", s)),
         };
 
-        let y = match y.parse() {
-            Ok(d) => d,
-            Err(_) => return Err(ParseError),
-        };
+        Ok(Point{x: error::parse(x)?, y: error::parse(y)?})
+    }
+}
 
-        Ok(Point{x: x, y: y})
+impl Debug for Cave {
+    /// Shows each row of the cave as a line of text, drawn the way the puzzle draws it.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let rows: Vec<String> = self.formation.iter().map(|row| row.iter().map(|state| format!("{:?}", state)).collect()).collect();
+        f.debug_struct("Cave")
+            .field("left", &self.left)
+            .field("right", &self.right)
+            .field("depth", &self.depth)
+            .field("formation", &rows)
+            .finish()
     }
 }
 
@@ -286,11 +287,7 @@ enum State{
 
 impl State {
     fn open(&self) -> bool {
-        match self {
-            Self::Source => true,
-            Self::Air => true,
-            _ => false,
-        }
+        matches!(self, Self::Source | Self::Air)
     }
 }
 
@@ -303,4 +300,57 @@ impl Debug for State {
             Self::Sand => f.write_char('o'),
         }
     }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "498,4 -> 498,6 -> 496,6
+503,4 -> 502,4 -> 502,9 -> 494,9";
+
+    #[test]
+    fn example_part_1() {
+        let input = Solution::parse(EXAMPLE).unwrap();
+        assert_eq!(Solution::solve_part_1(&input), Ok(Answer::Int(24)));
+    }
+
+    #[test]
+    fn example_part_2() {
+        let input = Solution::parse(EXAMPLE).unwrap();
+        assert_eq!(Solution::solve_part_2(&input), Ok(Answer::Int(93)));
+    }
+
+    #[test]
+    fn parse_formation() {
+        let formation: Formation = "498,4 -> 498,6 -> 496,6".parse().unwrap();
+        let points: Vec<(usize, usize)> = formation.points.iter().map(|p| (p.x, p.y)).collect();
+        assert_eq!(points, vec![(498, 4), (498, 6), (496, 6)]);
+
+        assert!("498,4 -> 498".parse::<Formation>().is_err());
+        assert!("498;4".parse::<Formation>().is_err());
+
+        let input = EXAMPLE.replace("502,9", "502,-9");
+        let e = Solution::parse(&input).unwrap_err().locate(&input);
+        assert_eq!((e.line, e.column, e.text.as_str()), (Some(2), Some(23), "-9"));
+    }
+
+    #[test]
+    fn cave_bounds() {
+        let cave = Cave::new(parse_formations(EXAMPLE).unwrap());
+
+        // One column of air is kept either side of the outermost rock
+        assert_eq!((cave.left, cave.right, cave.depth), (493, 504, 9));
+        assert!(matches!(cave.formation[9][494-cave.left], State::Rock));
+        assert!(matches!(cave.formation[0][500-cave.left], State::Source));
+    }
+    #[test]
+    fn dump_rows() {
+        let cave = Cave::new(parse_formations(EXAMPLE).unwrap());
+        let dump = format!("{:#?}", cave);
+
+        assert_eq!(dump.lines().count(), 17, "{}", dump);
+        assert!(dump.contains("\".......+....\","), "{}", dump);
+        assert!(dump.contains("\".#########..\","), "{}", dump);
+    }
 }
\ No newline at end of file
@@ -0,0 +1,211 @@
+use std::{collections::HashSet, str::FromStr};
+
+use crate::{error::{self, InputError}, solver::{Answer, Solver}};
+
+pub struct Solution;
+
+impl Solver for Solution {
+    type Input = Vec<Movement>;
+
+    fn parse(input: &str) -> Result<Self::Input, InputError> {
+        parse_movements(input)
+    }
+
+    fn solve_part_1(movements: &Self::Input) -> Result<Answer, InputError> {
+        let mut rope = Rope::new(1);
+
+        for movement in movements {
+            rope.update(movement);
+        }
+
+        Ok(rope.tail_history.len().into())
+    }
+
+    fn solve_part_2(movements: &Self::Input) -> Result<Answer, InputError> {
+        let mut rope = Rope::new(9);
+
+        for movement in movements {
+            rope.update(movement);
+        }
+
+        Ok(rope.tail_history.len().into())
+    }
+}
+
+struct Rope {
+    head: Position,
+    knots: Vec<Position>,
+
+    tail_history: HashSet<Position>,
+}
+
+impl Rope {
+    fn new(length: usize) -> Self {
+        let mut rope = Rope{
+            head: Position{x: 0, y: 0},
+            knots: vec![Position{x: 0, y: 0}; length],
+            tail_history: HashSet::new(),
+        };
+        
+        rope.tail_history.insert(Position{x: 0, y: 0});
+
+        rope
+    }
+
+    fn update(&mut self, m: &Movement) {
+        match *m {
+            Movement::Up(d) => {
+                for _ in 0..d {
+                    self.step(Position { x: 0, y: 1 });
+                }
+            }
+            Movement::Down(d) => {
+                for _ in 0..d {
+                    self.step(Position { x: 0, y: -1 });
+                }
+            }
+            Movement::Left(d) => {
+                for _ in 0..d {
+                    self.step(Position { x: -1, y: 0 });
+                }
+            }
+            Movement::Right(d) => {
+                for _ in 0..d {
+                    self.step(Position { x: 1, y: 0 });
+                }
+            }
+        }
+    }
+
+    fn step (&mut self, head_update: Position) {
+        self.head = Position{x: self.head.x+head_update.x, y: self.head.y+head_update.y};
+
+        let mut prev_knot = Position{x: self.head.x, y: self.head.y};
+        for knot in self.knots.iter_mut() {
+            let x_change = prev_knot.x-knot.x;
+            let y_change = prev_knot.y-knot.y;
+
+            if x_change.abs() < 2 && y_change.abs() < 2 {
+                break
+            }
+
+            knot.x += x_change.signum();
+            knot.y += y_change.signum();
+            prev_knot = Position{x: knot.x, y: knot.y};
+        }
+
+        if let Some(knot) = self.knots.last() {
+            self.tail_history.insert(Position { x: knot.x, y: knot.y });
+        }
+        
+    }
+}
+
+#[derive(Clone, Hash, Eq, PartialEq, Debug)]
+struct Position {
+    x: i32,
+    y: i32,
+}
+
+fn parse_movements(input: &str) -> Result<Vec<Movement>, InputError> {
+    let mut vec = Vec::new();
+
+    for line in input.lines() {
+        vec.push(line.parse()?);
+    }
+
+    Ok(vec)
+}
+
+#[derive(Debug)]
+pub enum Movement {
+    Up(i32),
+    Down(i32),
+    Left(i32),
+    Right(i32),
+}
+
+impl FromStr for Movement {
+    type Err = InputError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.split_once(" ") {
+            Some((direction, distance)) => {
+                let distance = error::parse(distance)?;
+
+                match direction {
+                    "U" => Ok(Movement::Up(distance)),
+                    "D" => Ok(Movement::Down(distance)),
+                    "L" => Ok(Movement::Left(distance)),
+                    "R" => Ok(Movement::Right(distance)),
+                    _ => Err(InputError::new("unknown direction", direction)),
+                }
+            }
+            None => Err(InputError::new("expected `<direction> <distance>`", s)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "R 4
+U 4
+L 3
+D 1
+R 4
+D 1
+L 5
+R 2";
+
+    const LARGER_EXAMPLE: &str = "R 5
+U 8
+L 8
+D 3
+R 17
+D 10
+L 25
+U 20";
+
+    #[test]
+    fn example_part_1() {
+        let input = Solution::parse(EXAMPLE).unwrap();
+        assert_eq!(Solution::solve_part_1(&input), Ok(Answer::Int(13)));
+    }
+
+    #[test]
+    fn example_part_2() {
+        let input = Solution::parse(EXAMPLE).unwrap();
+        assert_eq!(Solution::solve_part_2(&input), Ok(Answer::Int(1)));
+        let input = Solution::parse(LARGER_EXAMPLE).unwrap();
+        assert_eq!(Solution::solve_part_2(&input), Ok(Answer::Int(36)));
+    }
+
+    #[test]
+    fn parse_movement() {
+        assert!(matches!("U 4".parse(), Ok(Movement::Up(4))));
+        assert!(matches!("D 12".parse(), Ok(Movement::Down(12))));
+        assert!(matches!("L 1".parse(), Ok(Movement::Left(1))));
+        assert!(matches!("R 7".parse(), Ok(Movement::Right(7))));
+
+        assert!("X 1".parse::<Movement>().is_err());
+        assert!("U".parse::<Movement>().is_err());
+        assert!("U a".parse::<Movement>().is_err());
+    }
+
+    #[test]
+    fn rope_step() {
+        let mut rope = Rope::new(1);
+
+        // Adjacent knots, including diagonals, don't move the tail
+        rope.step(Position{x: 1, y: 0});
+        rope.step(Position{x: 0, y: 1});
+        assert_eq!(rope.knots[0], Position{x: 0, y: 0});
+
+        // Once the head is two away the tail catches up diagonally
+        rope.step(Position{x: 0, y: 1});
+        assert_eq!(rope.knots[0], Position{x: 1, y: 1});
+        assert_eq!(rope.tail_history.len(), 2);
+    }
+}
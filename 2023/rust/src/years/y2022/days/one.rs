@@ -0,0 +1,83 @@
+use std::collections::BinaryHeap;
+
+use crate::{error::{self, InputError}, solver::{Answer, Solver}};
+
+pub struct Solution;
+
+impl Solver for Solution {
+    /// Each elf's total calories, most first.
+    type Input = Vec<u32>;
+
+    fn parse(input: &str) -> Result<Self::Input, InputError> {
+        Ok(elf_calories(input)?.into_sorted_vec().into_iter().rev().collect())
+    }
+
+    fn solve_part_1(elf_inventory: &Self::Input) -> Result<Answer, InputError> {
+        Ok(most_calories(elf_inventory, 1).into())
+    }
+
+    fn solve_part_2(elf_inventory: &Self::Input) -> Result<Answer, InputError> {
+        Ok(most_calories(elf_inventory, 3).into())
+    }
+}
+
+fn elf_calories(calorie_list: &str) -> Result<BinaryHeap<u32>, InputError> {
+    let mut heap = BinaryHeap::new();
+    let mut calories = 0;
+    for line in calorie_list.lines() {
+        if line.is_empty() {
+            heap.push(calories);
+            calories = 0;
+            continue;
+        }
+        calories += error::parse::<u32>(line)?;
+    }
+    heap.push(calories);
+    Ok(heap)
+}
+
+fn most_calories(elf_calories: &[u32], elves: usize) -> u32 {
+    elf_calories.iter().take(elves).sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "1000
+2000
+3000
+
+4000
+
+5000
+6000
+
+7000
+8000
+9000
+
+10000";
+
+    #[test]
+    fn example_part_1() {
+        let input = Solution::parse(EXAMPLE).unwrap();
+        assert_eq!(Solution::solve_part_1(&input), Ok(Answer::Int(24000)));
+    }
+
+    #[test]
+    fn example_part_2() {
+        let input = Solution::parse(EXAMPLE).unwrap();
+        assert_eq!(Solution::solve_part_2(&input), Ok(Answer::Int(45000)));
+    }
+
+    #[test]
+    fn bad_calories() {
+        let input = "1000
+
+20x0
+";
+        let e = Solution::parse(input).unwrap_err().locate(input);
+        assert_eq!((e.line, e.column, e.text.as_str()), (Some(3), Some(1), "20x0"));
+    }
+}
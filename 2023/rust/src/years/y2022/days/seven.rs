@@ -0,0 +1,277 @@
+use std::{str::FromStr, collections::HashMap};
+
+use log::debug;
+
+use crate::{error::{self, InputError}, solver::{Answer, Solver}};
+
+const DISK_SPACE: u32 = 70_000_000;
+/// Free space the update needs.
+const UPDATE_SPACE: u32 = 30_000_000;
+
+pub struct Solution;
+
+impl Solver for Solution {
+    /// The top level directory.
+    type Input = Dir;
+
+    fn parse(input: &str) -> Result<Self::Input, InputError> {
+        explore(input)
+    }
+
+    fn solve_part_1(dir: &Self::Input) -> Result<Answer, InputError> {
+        let dir_sizes = dir.dir_sizes();
+        Ok(dir_sizes.iter().filter(|s| **s <= 100_000).sum::<u32>().into())
+    }
+
+    fn solve_part_2(dir: &Self::Input) -> Result<Answer, InputError> {
+        let free_space = match DISK_SPACE.checked_sub(dir.size()) {
+            Some(free) => free,
+            None => return Err(InputError::unlocated(format!("{} bytes of files don't fit on a {} byte disk", dir.size(), DISK_SPACE))),
+        };
+        let required_space = match UPDATE_SPACE.checked_sub(free_space) {
+            Some(required) if required > 0 => required,
+            _ => return Err(InputError::unlocated(format!("{} bytes are already free, nothing needs deleting", free_space))),
+        };
+        debug!("required space: {}", required_space);
+
+        let dir_sizes = dir.dir_sizes();
+        match dir_sizes.iter().filter(|s| **s >= required_space).min() {
+            Some(size) => Ok((*size).into()),
+            None => Err(InputError::unlocated(format!("no directory frees the {} bytes needed", required_space))),
+        }
+    }
+}
+
+/// Replays the terminal output, returning the top level directory.
+fn explore(input: &str) -> Result<Dir, InputError> {
+    let mut dir = Dir::new(String::from("/"), None);
+
+    for line in input.lines() {
+        let (prefix, rest) = match line.split_once(" ") {
+            Some(split) => split,
+            None => return Err(InputError::new("line without prefix", line)),
+        };
+
+        match prefix {
+            "$" => {
+                match rest.parse::<Command>()? {
+                    Command::CD(cd_arg) => match cd_arg {
+                        CDArg::Top => {
+                            debug!("cd /");
+                            dir = dir.top()
+                        }
+                        CDArg::Out => {
+                            debug!("cd ..");
+                            dir = dir.parent()
+                        }
+                        CDArg::In(s) => {
+                            debug!("cd {}", s);
+                            dir = dir.child(s);
+                        }
+                    }
+                    Command::LS => debug!("ls"),
+                }
+            }
+            "dir" => (),
+            _ => dir.file(String::from(rest), error::parse(prefix)?),
+        }
+    }
+
+    Ok(dir.top())
+}
+
+#[derive(Debug)]
+pub struct Dir {
+    name: String,
+    parent: Option<Box<Self>>,
+    children: HashMap<String, Box<Self>>,
+    files: HashMap<String, u32>,
+}
+
+impl Dir {
+    fn new(name: String, parent: Option<Box<Dir>>) -> Self {
+        Self {
+            name,
+            parent,
+            children: HashMap::new(),
+            files: HashMap::new(),
+        }
+    }
+
+    fn file(&mut self, name: String, size: u32) {
+        self.files.entry(name).or_insert(size);
+    }
+
+    fn child(mut self, name: String) -> Self {
+        match self.children.remove(&name) {
+            Some(mut child) => {
+                child.parent = Some(Box::new(self));
+                *child
+            }
+            None => {
+                Dir::new(name, Some(Box::new(self)))
+            }
+        }
+    }
+
+    fn parent(mut self) -> Self {
+        match self.parent {
+            Some(mut parent) => {
+                self.parent = None;
+                parent.children.insert(self.name.clone(), Box::new(self));
+                *parent
+            }
+            None => self,
+        }
+    }
+
+    fn top(self) -> Self {
+        let mut curr = self;
+        loop {
+            match curr.parent {
+                Some(mut parent) => {
+                    curr.parent = None;
+                    parent.children.insert(curr.name.clone(), Box::new(curr));
+                    curr = *parent
+                }
+                None => return curr,
+            }
+        }
+    }
+    
+    fn size(&self) -> u32 {
+        let mut sum = 0;
+
+        for size in self.files.values() {
+            sum += size;
+        }
+
+        for dir in self.children.values() {
+            sum += dir.size();
+        }
+
+        sum
+    }
+
+    fn dir_sizes(&self) -> Vec<u32> {
+        let mut vec = Vec::new();
+
+        vec.push(self.size());
+
+        for child in self.children.values() {
+            vec.append(&mut child.dir_sizes());
+        }
+
+        vec
+    }
+}
+ 
+
+enum CDArg {
+    In(String),
+    Out,
+    Top,
+}
+
+enum Command {
+    CD(CDArg),
+    LS
+}
+
+impl FromStr for Command {
+    type Err = InputError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.split_once(" ") {
+            Some((command, args)) => match (command, args) {
+                ("cd", "..") => Ok(Command::CD(CDArg::Out)),
+                ("cd", "/") => Ok(Command::CD(CDArg::Top)),
+                ("cd", _) => Ok(Command::CD(CDArg::In(args.to_string()))),
+                (_, _) => Err(InputError::new("unknown command", s)),
+            }
+            None => match s {
+                "ls" => Ok(Command::LS),
+                _ => Err(InputError::new("unknown command", s)),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "$ cd /
+$ ls
+dir a
+14848514 b.txt
+8504156 c.dat
+dir d
+$ cd a
+$ ls
+dir e
+29116 f
+2557 g
+62596 h.lst
+$ cd e
+$ ls
+584 i
+$ cd ..
+$ cd ..
+$ cd d
+$ ls
+4060174 j
+8033020 d.log
+5626152 d.ext
+7214296 k";
+
+    #[test]
+    fn example_part_1() {
+        let input = Solution::parse(EXAMPLE).unwrap();
+        assert_eq!(Solution::solve_part_1(&input), Ok(Answer::Int(95437)));
+    }
+
+    #[test]
+    fn example_part_2() {
+        let input = Solution::parse(EXAMPLE).unwrap();
+        assert_eq!(Solution::solve_part_2(&input), Ok(Answer::Int(24933642)));
+    }
+
+    #[test]
+    fn dir_navigation() {
+        let mut dir = Dir::new(String::from("/"), None);
+        dir.file(String::from("a"), 10);
+        dir = dir.child(String::from("b"));
+        dir.file(String::from("c"), 5);
+        dir = dir.child(String::from("d"));
+        dir.file(String::from("e"), 1);
+        dir = dir.top();
+
+        assert_eq!(dir.name, "/");
+        assert_eq!(dir.size(), 16);
+
+        let mut sizes = dir.dir_sizes();
+        sizes.sort();
+        assert_eq!(sizes, vec![1, 6, 16]);
+    }
+
+    #[test]
+    fn bad_terminal_output() {
+        let input = EXAMPLE.replace("$ cd e", "$ pwd");
+        let e = Solution::parse(&input).unwrap_err().locate(&input);
+        assert_eq!((e.line, e.column, e.text.as_str()), (Some(13), Some(3), "pwd"));
+
+        let input = EXAMPLE.replace("2557 g", "2.5k g");
+        let e = Solution::parse(&input).unwrap_err().locate(&input);
+        assert_eq!((e.line, e.column, e.text.as_str()), (Some(11), Some(1), "2.5k"));
+    }
+    #[test]
+    fn nothing_to_delete() {
+        let input = "$ cd /\n$ ls\n1 a";
+        let dir = Solution::parse(input).unwrap();
+        assert_eq!(Solution::solve_part_2(&dir).unwrap_err().to_string(), "input: 69999999 bytes are already free, nothing needs deleting");
+
+        let dir = Solution::parse("$ cd /\n$ ls\n70000001 a").unwrap();
+        assert_eq!(Solution::solve_part_2(&dir).unwrap_err().to_string(), "input: 70000001 bytes of files don't fit on a 70000000 byte disk");
+    }
+}
\ No newline at end of file
@@ -0,0 +1,82 @@
+use std::collections::HashMap;
+
+use crate::{error::InputError, solver::{Answer, Solver}};
+
+pub struct Solution;
+
+impl Solver for Solution {
+    type Input = Vec<char>;
+
+    fn parse(input: &str) -> Result<Self::Input, InputError> {
+        Ok(input.trim_end().chars().collect())
+    }
+
+    fn solve_part_1(chars: &Self::Input) -> Result<Answer, InputError> {
+        Ok(start_of_marker(chars, 4)?.into())
+    }
+
+    fn solve_part_2(chars: &Self::Input) -> Result<Answer, InputError> {
+        Ok(start_of_marker(chars, 14)?.into())
+    }
+}
+
+fn start_of_marker(chars: &[char], num_chars: usize) -> Result<usize, InputError> {
+    let mut map: HashMap<char, u32> = HashMap::new();
+
+    // range and update
+    for i in 0..chars.len() {
+        if i >= num_chars {
+            if let Some(c) = chars.get(i-num_chars) {
+                if let Some(count) = map.get_mut(c) {
+                    *count -= 1;
+                    if *count == 0 {
+                        map.remove(c);
+                    }
+                }
+            }
+        }
+        
+        *map.entry(chars[i]).or_default() += 1;
+
+        if map.len() == num_chars {
+            return Ok(i + 1);
+        }
+    }
+
+    Err(InputError::unlocated(format!("no run of {} different characters", num_chars)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLES: [(&str, usize, usize); 5] = [
+        ("mjqjpqmgbljsphdztnvjfqwrcgsmlb", 7, 19),
+        ("bvwbjplbgvbhsrlpgdmjqwftvncz", 5, 23),
+        ("nppdvjthqldpwncqszvftbrmjlhg", 6, 23),
+        ("nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg", 10, 29),
+        ("zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw", 11, 26),
+    ];
+
+    #[test]
+    fn example_part_1() {
+        for (input, marker, _) in EXAMPLES {
+            let chars = Solution::parse(input).unwrap();
+            assert_eq!(Solution::solve_part_1(&chars), Ok(marker.into()), "{}", input);
+        }
+    }
+
+    #[test]
+    fn example_part_2() {
+        for (input, _, marker) in EXAMPLES {
+            let chars = Solution::parse(input).unwrap();
+            assert_eq!(Solution::solve_part_2(&chars), Ok(marker.into()), "{}", input);
+        }
+    }
+
+    #[test]
+    fn no_marker() {
+        let chars = Solution::parse("abcabcabc\n").unwrap();
+        assert!(Solution::solve_part_1(&chars).is_err());
+    }
+}
\ No newline at end of file
@@ -0,0 +1,354 @@
+use std::str::FromStr;
+
+use crate::{error::{self, InputError}, solver::{Answer, Solver}};
+
+pub struct Solution;
+
+impl Solver for Solution {
+    type Input = Vec<Instruction>;
+
+    fn parse(input: &str) -> Result<Self::Input, InputError> {
+        parse_instructions(input)
+    }
+
+    fn solve_part_1(instructions: &Self::Input) -> Result<Answer, InputError> {
+        let mut cpu = Cpu{register_x: 1, cycle: 0};
+
+        let mut signal_strength = 0;
+        let mut next_cycle = 20;
+        for ins in instructions {
+            let out = cpu.process(*ins, next_cycle);
+            if out > 0 {
+                next_cycle += 40;
+                signal_strength += out;
+            }
+        }
+
+        Ok(signal_strength.into())
+    }
+
+    fn solve_part_2(instructions: &Self::Input) -> Result<Answer, InputError> {
+        let mut screen = Screen{register_x: 1, cycle: 0, lines: [['.'; 40]; 6]};
+
+        for ins in instructions {
+            screen.process(*ins);
+        }
+
+        Ok(screen.display())
+    }
+}
+
+/// Cycles the screen draws, one per pixel.
+const SCREEN_CYCLES: u16 = 240;
+
+fn parse_instructions(input: &str) -> Result<Vec<Instruction>, InputError> {
+    let mut vec = Vec::new();
+    let mut cycles = 0;
+
+    for line in input.lines() {
+        let ins = line.parse()?;
+        cycles += match ins {
+            Instruction::Addx(_) => 2,
+            Instruction::Noop => 1,
+        };
+        // The screen has nowhere to draw any later cycles
+        if cycles > SCREEN_CYCLES {
+            return Err(InputError::new(format!("program runs past the screen's {} cycles", SCREEN_CYCLES), line));
+        }
+        vec.push(ins);
+    }
+
+    Ok(vec)
+}
+
+struct Screen {
+    register_x: i32,
+    cycle: u16,
+    lines: [[char; 40]; 6],
+}
+
+impl Screen {
+    fn process(&mut self, ins: Instruction) {
+        match ins {
+            Instruction::Addx(val) => {
+                let line = self.cycle as usize / 40;
+                let c = self.cycle as usize % 40;
+
+                if self.register_x-1 <= c as i32 && c as i32 <= self.register_x+1 {
+                    self.lines[line][c] = '#';
+                }
+
+                self.cycle += 1;
+
+                let line = self.cycle as usize / 40;
+                let c = self.cycle as usize % 40;
+                if self.register_x-1 <= (c) as i32 && (c) as i32 <= self.register_x+1 {
+                    self.lines[line][c] = '#';
+                }
+                
+                self.cycle += 1;
+                self.register_x += val;
+            }
+            Instruction::Noop => {
+                let line = self.cycle as usize / 40;
+                let c = self.cycle as usize % 40;
+                if self.register_x-1 <= c as i32 && c as i32 <= self.register_x+1 {
+                    self.lines[line][c] = '#';
+                }
+
+                self.cycle += 1;
+            },
+        }
+    }
+
+    fn display(&self) -> Answer {
+        Answer::Art(self.lines.iter().map(String::from_iter).collect())
+    }
+}
+
+struct Cpu {
+    register_x: i32,
+    cycle: u16,
+}
+
+impl Cpu {
+    fn process(&mut self, ins: Instruction, cycle_report: u16) -> i32 {
+        match ins {
+            Instruction::Addx(val) => {
+                let mut ret = 0;
+
+                self.cycle += 2;
+                if self.cycle >= cycle_report {
+                    ret = self.register_x * cycle_report as i32;
+                }
+                self.register_x += val;
+
+                ret
+            }
+            Instruction::Noop => {
+                self.cycle += 1;
+                if self.cycle == cycle_report {
+                    return self.register_x * self.cycle as i32;
+                }
+
+                0
+            },
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub enum Instruction {
+    Addx(i32),
+    Noop,
+}
+
+impl FromStr for Instruction {
+    type Err = InputError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.split_once(" ") {
+            Some((ins, value)) => {
+                match ins {
+                    "addx" => Ok(Instruction::Addx(error::parse(value)?)),
+                    _ => Err(InputError::new("unknown instruction", ins)),
+                }
+            }
+            None => match s {
+                "noop" => Ok(Instruction::Noop),
+                _ => Err(InputError::new("unknown instruction", s)),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "addx 15
+addx -11
+addx 6
+addx -3
+addx 5
+addx -1
+addx -8
+addx 13
+addx 4
+noop
+addx -1
+addx 5
+addx -1
+addx 5
+addx -1
+addx 5
+addx -1
+addx 5
+addx -1
+addx -35
+addx 1
+addx 24
+addx -19
+addx 1
+addx 16
+addx -11
+noop
+noop
+addx 21
+addx -15
+noop
+noop
+addx -3
+addx 9
+addx 1
+addx -3
+addx 8
+addx 1
+addx 5
+noop
+noop
+noop
+noop
+noop
+addx -36
+noop
+addx 1
+addx 7
+noop
+noop
+noop
+addx 2
+addx 6
+noop
+noop
+noop
+noop
+noop
+addx 1
+noop
+noop
+addx 7
+addx 1
+noop
+addx -13
+addx 13
+addx 7
+noop
+addx 1
+addx -33
+noop
+noop
+noop
+addx 2
+noop
+noop
+noop
+addx 8
+noop
+addx -1
+addx 2
+addx 1
+noop
+addx 17
+addx -9
+addx 1
+addx 1
+addx -3
+addx 11
+noop
+noop
+addx 1
+noop
+addx 1
+noop
+noop
+addx -13
+addx -19
+addx 1
+addx 3
+addx 26
+addx -30
+addx 12
+addx -1
+addx 3
+addx 1
+noop
+noop
+noop
+addx -9
+addx 18
+addx 1
+addx 2
+noop
+noop
+addx 9
+noop
+noop
+noop
+addx -1
+addx 2
+addx -37
+addx 1
+addx 3
+noop
+addx 15
+addx -21
+addx 22
+addx -6
+addx 1
+noop
+addx 2
+addx 1
+noop
+addx -10
+noop
+noop
+addx 20
+addx 1
+addx 2
+addx 2
+addx -6
+addx -11
+noop
+noop
+noop";
+
+    #[test]
+    fn example_part_1() {
+        let input = Solution::parse(EXAMPLE).unwrap();
+        assert_eq!(Solution::solve_part_1(&input), Ok(Answer::Int(13140)));
+    }
+
+    #[test]
+    fn example_part_2() {
+        let input = Solution::parse(EXAMPLE).unwrap();
+        assert_eq!(Solution::solve_part_2(&input), Ok(Answer::Art(vec![
+            String::from("##..##..##..##..##..##..##..##..##..##.."),
+            String::from("###...###...###...###...###...###...###."),
+            String::from("####....####....####....####....####...."),
+            String::from("#####.....#####.....#####.....#####....."),
+            String::from("######......######......######......####"),
+            String::from("#######.......#######.......#######....."),
+        ])));
+    }
+
+    #[test]
+    fn parse_instruction() {
+        assert!(matches!("noop".parse(), Ok(Instruction::Noop)));
+        assert!(matches!("addx 3".parse(), Ok(Instruction::Addx(3))));
+        assert!(matches!("addx -5".parse(), Ok(Instruction::Addx(-5))));
+
+        assert!("addx".parse::<Instruction>().is_err());
+        assert!("addx x".parse::<Instruction>().is_err());
+        assert!("subx 3".parse::<Instruction>().is_err());
+    }
+    #[test]
+    fn program_too_long() {
+        let input = "noop\n".repeat(300);
+        let e = Solution::parse(&input).unwrap_err().locate(&input);
+        assert_eq!(e.to_string(), "line 241, column 1: program runs past the screen's 240 cycles: `noop`");
+
+        let input = format!("{}addx 1", "noop\n".repeat(239));
+        assert_eq!(Solution::parse(&input).unwrap_err().locate(&input).line, Some(240));
+    }
+}
\ No newline at end of file
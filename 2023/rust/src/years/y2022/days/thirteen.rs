@@ -0,0 +1,236 @@
+use std::{str::FromStr, collections::VecDeque};
+
+use log::debug;
+
+use crate::{error::{self, InputError}, solver::{Answer, Solver}};
+
+pub struct Solution;
+
+impl Solver for Solution {
+    type Input = Vec<PacketPair>;
+
+    fn parse(input: &str) -> Result<Self::Input, InputError> {
+        parse_packetdata_set(input)
+    }
+
+    fn solve_part_1(pairs: &Self::Input) -> Result<Answer, InputError> {
+        let mut sum = 0;
+        for (i, pair) in pairs.iter().enumerate() {
+            debug!("{:?}", pair);
+            let in_order = pair.in_order();
+            debug!("{}: {}", i+1, in_order);
+            if in_order {
+                sum += i+1;
+            }
+        }
+
+        Ok(sum.into())
+    }
+
+    fn solve_part_2(pairs: &Self::Input) -> Result<Answer, InputError> {
+        let pd2 = PacketData::List(vec![PacketData::Int(2)]);
+        let pd6 = PacketData::List(vec![PacketData::Int(6)]);
+
+        let mut packets: Vec<PacketData> = pairs.iter().flat_map(|p| [p.first.clone(), p.second.clone()]).collect();
+        packets.push(pd2.clone());
+        packets.push(pd6.clone());
+        packets.sort();
+
+        let idx2 = match packets.binary_search(&pd2) {
+            Ok(v) => v,
+            Err(_) => panic!("should be found")
+        };
+        let idx6 = match packets.binary_search(&pd6) {
+            Ok(v) => v,
+            Err(_) => panic!("should be found")
+        };
+
+        Ok(((idx2+1) * (idx6+1)).into())
+    }
+}
+
+fn parse_packetdata_set(input: &str) -> Result<Vec<PacketPair>, InputError> {
+    let mut vec = Vec::new();
+
+    for pair in input.split("\n\n") {
+        let (first, second) = match pair.trim_end().split_once("\n") {
+            Some(split) => split,
+            None => return Err(InputError::new("expected a pair of packets", pair)),
+        };
+
+        vec.push(PacketPair { first: first.parse()?, second: second.parse()? });
+    }
+
+    Ok(vec)
+}
+
+#[derive(Debug)]
+pub struct PacketPair {
+    first: PacketData,
+    second: PacketData,
+}
+
+impl PacketPair {
+    fn in_order(&self) -> bool {
+        self.first <= self.second
+    }
+}
+
+#[derive(Clone, Debug)]
+enum PacketData {
+    Int(u32),
+    List(Vec<Self>),
+}
+
+impl PacketData {
+    /// Parses the rest of a list whose `[` was at `open` in `s`, consuming
+    /// up to and including its `]`.
+    fn subparse(s: &str, chars: &mut VecDeque<(usize, char)>, open: usize) -> Result<Self, InputError> {
+        let mut data = Vec::new();
+
+        while let Some((i, c)) = chars.pop_front() {
+            match c {
+                ',' => continue,
+                '[' => data.push(PacketData::subparse(s, chars, i)?),
+                ']' => return Ok(PacketData::List(data)),
+                '0'..='9' => {
+                    let mut end = i + 1;
+                    while let Some((j, c)) = chars.front() {
+                        if !c.is_ascii_digit() {
+                            break
+                        }
+                        end = j + 1;
+                        chars.pop_front();
+                    }
+
+                    data.push(PacketData::Int(error::parse(&s[i..end])?));
+                }
+                _ => return Err(InputError::new("unexpected character in packet", &s[i..i+c.len_utf8()])),
+            }
+        }
+
+        Err(InputError::new("unclosed list", &s[open..open+1]))
+    }
+}
+
+impl PartialEq for PacketData {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Int(i1), Self::Int(i2)) => i1.eq(i2),
+            (Self::Int(_), Self::List(_)) => PacketData::List(vec![self.clone()]).eq(other),
+            (Self::List(_), Self::Int(_)) => self.eq(&PacketData::List(vec![other.clone()])),
+            (Self::List(l1), Self::List(l2)) => l1.eq(l2),
+        }
+    }
+}
+
+impl PartialOrd for PacketData {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Eq for PacketData {}
+
+impl Ord for PacketData {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        match (self, other) {
+            (Self::Int(i1), Self::Int(i2)) => i1.cmp(i2),
+            (Self::Int(_), Self::List(_)) => PacketData::List(vec![self.clone()]).cmp(other),
+            (Self::List(_), Self::Int(_)) => self.cmp(&PacketData::List(vec![other.clone()])),
+            (Self::List(l1), Self::List(l2)) => l1.cmp(l2),
+        }
+    }
+}
+
+impl FromStr for PacketData {
+    type Err = InputError;
+    
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut chars: VecDeque<(usize, char)> = s.char_indices().collect();
+
+        match chars.pop_front() {
+            Some((i, '[')) => {
+                let data = PacketData::subparse(s, &mut chars, i)?;
+                match chars.front() {
+                    Some((j, _)) => Err(InputError::new("unexpected text after packet", &s[*j..])),
+                    None => Ok(data),
+                }
+            }
+            _ => Err(InputError::new("expected a packet starting with `[`", s)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "[1,1,3,1,1]
+[1,1,5,1,1]
+
+[[1],[2,3,4]]
+[[1],4]
+
+[9]
+[[8,7,6]]
+
+[[4,4],4,4]
+[[4,4],4,4,4]
+
+[7,7,7,7]
+[7,7,7]
+
+[]
+[3]
+
+[[[]]]
+[[]]
+
+[1,[2,[3,[4,[5,6,7]]]],8,9]
+[1,[2,[3,[4,[5,6,0]]]],8,9]";
+
+    fn packet(s: &str) -> PacketData {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn example_part_1() {
+        let input = Solution::parse(EXAMPLE).unwrap();
+        assert_eq!(Solution::solve_part_1(&input), Ok(Answer::Int(13)));
+    }
+
+    #[test]
+    fn example_part_2() {
+        let input = Solution::parse(EXAMPLE).unwrap();
+        assert_eq!(Solution::solve_part_2(&input), Ok(Answer::Int(140)));
+    }
+
+    #[test]
+    fn parse_packet_data() {
+        assert_eq!(packet("[]"), PacketData::List(vec![]));
+        assert_eq!(packet("[10,[2]]"), PacketData::List(vec![
+            PacketData::Int(10),
+            PacketData::List(vec![PacketData::Int(2)]),
+        ]));
+        assert!("10".parse::<PacketData>().is_err());
+        assert!("[1,[2]".parse::<PacketData>().is_err());
+        assert!("[1]]".parse::<PacketData>().is_err());
+
+        let input = EXAMPLE.replace("[[8,7,6]]", "[[8,7;6]]");
+        let e = Solution::parse(&input).unwrap_err().locate(&input);
+        assert_eq!((e.line, e.column, e.text.as_str()), (Some(8), Some(6), ";"));
+    }
+
+    #[test]
+    fn packet_data_ordering() {
+        // Integers compare against lists as if wrapped in a single element list
+        assert_eq!(packet("[[1],[2,3,4]]").cmp(&packet("[[1],4]")), std::cmp::Ordering::Less);
+        assert_eq!(packet("[9]").cmp(&packet("[[8,7,6]]")), std::cmp::Ordering::Greater);
+        assert_eq!(packet("[[2]]"), packet("[2]"));
+
+        // Running out of items first makes a list smaller
+        assert!(packet("[7,7,7]") < packet("[7,7,7,7]"));
+        assert!(packet("[[]]") < packet("[[[]]]"));
+    }
+}
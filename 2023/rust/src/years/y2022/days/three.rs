@@ -0,0 +1,149 @@
+use std::collections::HashSet;
+
+use crate::{error::InputError, solver::{Answer, Solver}};
+
+const PRIORITY_STRING: &str = "abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+fn priority(c: &char) -> u32 {
+    match PRIORITY_STRING.find(*c) {
+        Some(index) => u32::try_from(index + 1).unwrap(),
+        None => 0,
+    }
+}
+
+fn letters(s: &str) -> HashSet<char> {
+    let mut set = HashSet::new();
+
+    for c in s.chars() {
+        set.insert(c);
+    }
+
+    set
+}
+
+fn rucksacks(s: &str) -> Result<Vec<String>, InputError> {
+    let mut v = Vec::new();
+
+    for line in s.lines() {
+        if let Some((i, c)) = line.char_indices().find(|(_, c)| !c.is_ascii_alphabetic()) {
+            return Err(InputError::new("items are letters", &line[i..i+c.len_utf8()]));
+        }
+
+        v.push(line.to_string());
+    }
+
+    Ok(v)
+}
+
+fn itemize(rucksacks: &[String]) -> Result<Vec<char>, InputError> {
+    let mut v = Vec::new();
+
+    for (i, line) in rucksacks.iter().enumerate() {
+        let (part_a, part_b) = line.split_at(line.len()/2);
+        let letters_a = letters(part_a);
+        let letters_b = letters(part_b);
+        match letters_a.intersection(&letters_b).next() {
+            Some(c) => v.push(*c),
+            None => return Err(InputError::unlocated(format!("no item is in both compartments of rucksack {}", i+1))),
+        }
+    }
+
+    Ok(v)
+}
+
+fn badges(rucksacks: &[String]) -> Result<Vec<char>, InputError> {
+    let mut v = Vec::new();
+
+    for (i, group) in rucksacks.chunks(3).enumerate() {
+        let (line_a, line_b, line_c) = match group {
+            [a, b, c] => (a, b, c),
+            _ => return Err(InputError::unlocated(format!("group {} has fewer than three elves", i+1))),
+        };
+
+        let letters_a = letters(line_a);
+        let letters_b = letters(line_b);
+        let letters_c = letters(line_c);
+
+        match letters_a.into_iter().find(|c| letters_b.contains(c) && letters_c.contains(c)) {
+            Some(c) => v.push(c),
+            None => return Err(InputError::unlocated(format!("group {} has no badge in common", i+1))),
+        }
+    }
+
+    Ok(v)
+}
+
+pub struct Solution;
+
+impl Solver for Solution {
+    type Input = Vec<String>;
+
+    fn parse(input: &str) -> Result<Self::Input, InputError> {
+        rucksacks(input)
+    }
+
+    fn solve_part_1(rucksacks: &Self::Input) -> Result<Answer, InputError> {
+        let item_errors = itemize(rucksacks)?;
+
+        let mut sum = 0;
+        for err in item_errors {
+            sum += priority(&err);
+        }
+
+        Ok(sum.into())
+    }
+
+    fn solve_part_2(rucksacks: &Self::Input) -> Result<Answer, InputError> {
+        let bs = badges(rucksacks)?;
+
+        let mut sum = 0;
+        for err in bs {
+            sum += priority(&err);
+        }
+
+        Ok(sum.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "vJrwpWtwJgWrhcsFMMfFFhFp
+jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL
+PmmdzqPrVvPwwTWBwg
+wMqvLMZHhHMvwLHjbvcjnnSBnvTQFn
+ttgJtRGJQctTZtZT
+CrZsJsPPZsGzwwsLwLmpwMDw";
+
+    #[test]
+    fn example_part_1() {
+        let input = Solution::parse(EXAMPLE).unwrap();
+        assert_eq!(Solution::solve_part_1(&input), Ok(Answer::Int(157)));
+    }
+
+    #[test]
+    fn example_part_2() {
+        let input = Solution::parse(EXAMPLE).unwrap();
+        assert_eq!(Solution::solve_part_2(&input), Ok(Answer::Int(70)));
+    }
+
+    #[test]
+    fn bad_rucksacks() {
+        let input = "abcb\nab1b\n";
+        let e = Solution::parse(input).unwrap_err().locate(input);
+        assert_eq!((e.line, e.column, e.text.as_str()), (Some(2), Some(3), "1"));
+
+        let input = |s| Solution::parse(s).unwrap();
+        assert!(Solution::solve_part_1(&input("abcd")).is_err());
+        assert!(Solution::solve_part_2(&input("ab\nac")).is_err());
+        assert!(Solution::solve_part_2(&input("ab\ncd\nef")).is_err());
+    }
+
+    #[test]
+    fn priorities() {
+        assert_eq!(priority(&'p'), 16);
+        assert_eq!(priority(&'L'), 38);
+        assert_eq!(priority(&'!'), 0);
+    }
+}
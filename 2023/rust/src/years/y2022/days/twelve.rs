@@ -1,17 +1,33 @@
 use std::{collections::{HashSet, BinaryHeap}, hash::Hash, cmp::Ordering};
 
-use log::{info, debug};
+use log::debug;
 
-pub fn solve_part_1(input: &str) {
-    let positions = parse_chart(input);
-    let chart = Chart::new(positions, Elevation::Start);
-    info!("steps: {}", chart.navigate());
-}
+use crate::{error::InputError, solver::{Answer, Solver}};
+
+pub struct Solution;
+
+impl Solver for Solution {
+    type Input = Vec<Vec<Position>>;
+
+    fn parse(input: &str) -> Result<Self::Input, InputError> {
+        parse_chart(input)
+    }
 
-pub fn solve_part_2(input: &str) {
-    let positions = parse_chart(input);
-    let chart = Chart::new(positions, Elevation::Elevation(0));
-    info!("steps: {}", chart.navigate());
+    fn solve_part_1(positions: &Self::Input) -> Result<Answer, InputError> {
+        let chart = Chart::new(positions.clone(), Elevation::Start);
+        match chart.navigate() {
+            Some(steps) => Ok(steps.into()),
+            None => Err(InputError::unlocated("no path to the end")),
+        }
+    }
+
+    fn solve_part_2(positions: &Self::Input) -> Result<Answer, InputError> {
+        let chart = Chart::new(positions.clone(), Elevation::Height(0));
+        match chart.navigate() {
+            Some(steps) => Ok(steps.into()),
+            None => Err(InputError::unlocated("no path to the end")),
+        }
+    }
 }
 
 struct Chart {
@@ -53,7 +69,9 @@ impl Chart {
         }
     }
 
-    fn navigate(mut self) -> u32 {
+    /// The fewest steps from a starting position to the end, if it can be
+    /// reached at all.
+    fn navigate(mut self) -> Option<u32> {
         while let Some(curr) = self.next_positions.pop() {
             debug!("{},{}", curr.x, curr.y);
 
@@ -61,54 +79,38 @@ impl Chart {
     
             // check down
             if curr.x != 0 {
-                match self.elevations.get(curr.x-1) {
-                    Some(row) => match row.get(curr.y) {
-                        Some(pos) => if curr.elevation.can_navigate(&pos.elevation) {
-                            self.check_position(pos.clone(), next_steps);
-                        }
-                        None => (),
+                if let Some(pos) = self.elevations.get(curr.x-1).and_then(|row| row.get(curr.y)) {
+                    if curr.elevation.can_navigate(&pos.elevation) {
+                        self.check_position(pos.clone(), next_steps);
                     }
-                    None => (),
                 }
             }
     
             // check left
             if curr.y != 0 {
-                match self.elevations.get(curr.x) {
-                    Some(row) => match row.get(curr.y-1) {
-                        Some(pos) => if curr.elevation.can_navigate(&pos.elevation) {
-                            self.check_position(pos.clone(), next_steps);
-                        }
-                        None => (),
+                if let Some(pos) = self.elevations.get(curr.x).and_then(|row| row.get(curr.y-1)) {
+                    if curr.elevation.can_navigate(&pos.elevation) {
+                        self.check_position(pos.clone(), next_steps);
                     }
-                    None => (),
                 }
             }
     
             // check up
-            match self.elevations.get(curr.x+1) {
-                Some(row) => match row.get(curr.y) {
-                    Some(pos) => if curr.elevation.can_navigate(&pos.elevation) {
-                        self.check_position(pos.clone(), next_steps);
-                    }
-                    None => (),
+            if let Some(pos) = self.elevations.get(curr.x+1).and_then(|row| row.get(curr.y)) {
+                if curr.elevation.can_navigate(&pos.elevation) {
+                    self.check_position(pos.clone(), next_steps);
                 }
-                None => (),
             }
     
             // check right
-            match self.elevations.get(curr.x) {
-                Some(row) => match row.get(curr.y+1) {
-                    Some(pos) => if curr.elevation.can_navigate(&pos.elevation) {
-                        self.check_position(pos.clone(), next_steps);
-                    }
-                    None => (),
+            if let Some(pos) = self.elevations.get(curr.x).and_then(|row| row.get(curr.y+1)) {
+                if curr.elevation.can_navigate(&pos.elevation) {
+                    self.check_position(pos.clone(), next_steps);
                 }
-                None => (),
             }
         }
     
-        self.step_history.get(&self.end).expect("never stepped to end").steps.expect("never updated end steps")
+        self.step_history.get(&self.end).and_then(|end| end.steps)
     }
 
     fn check_position(&mut self, mut pos: Position, new_steps: u32) {
@@ -126,8 +128,8 @@ impl Chart {
     }
 }
 
-#[derive(Clone)]
-struct Position {
+#[derive(Clone, Debug)]
+pub struct Position {
     x: usize,
     y: usize,
     elevation: Elevation,
@@ -142,10 +144,7 @@ impl PartialEq for Position {
 
 impl PartialOrd for Position {
     fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
-        match self.steps.partial_cmp(&other.steps) {
-            None => None,
-            Some(ord) => Some(ord.reverse())
-        }
+        Some(self.cmp(other))
     }
 }
 
@@ -153,10 +152,7 @@ impl Eq for Position {}
 
 impl Ord for Position {
     fn cmp(&self, other: &Self) -> std::cmp::Ordering {
-        match self.elevation.cmp(&other.elevation).reverse() {
-            std::cmp::Ordering::Equal => self.steps.cmp(&other.steps).reverse(),
-            ord => ord,
-        }
+        self.steps.cmp(&other.steps).reverse()
     }
 }
 
@@ -167,36 +163,43 @@ impl Hash for Position {
     }
 }
 
-fn parse_chart(input: &str) -> Vec<Vec<Position>> {
+fn parse_chart(input: &str) -> Result<Vec<Vec<Position>>, InputError> {
     let mut vec = Vec::new();
 
     for (i, line) in input.lines().enumerate() {
         let mut row = Vec::new();
 
-        for (j, c) in line.chars().enumerate() {
-            row.push(Position{x: i, y: j, elevation: Elevation::from(c), steps: None});
+        for (j, (k, c)) in line.char_indices().enumerate() {
+            match c {
+                'S' | 'E' | 'a'..='z' => row.push(Position{x: i, y: j, elevation: Elevation::from(c), steps: None}),
+                _ => return Err(InputError::new("unknown elevation", &line[k..k+c.len_utf8()])),
+            }
         }
 
         vec.push(row);
     }
 
-    vec
+    if !input.contains('E') {
+        return Err(InputError::new("no ending position `E`", input.trim_end()));
+    }
+
+    Ok(vec)
 }
 
-#[derive(Clone, Hash)]
+#[derive(Clone, Debug)]
 enum Elevation {
     Start,
     End,
-    Elevation(u8),
+    Height(u8),
 }
 
 impl Elevation {
     fn can_navigate(&self, other: &Self) -> bool {
         match (self, other) {
             (_, Self::Start) => true,
-            (Self::Start, Self::Elevation(e)) => *e <= 1,
-            (Self::Elevation(e1), Self::Elevation(e2)) => *e1 + 1 >= *e2,
-            (Self::Elevation(e), Self::End) => e + 1 >= 26,
+            (Self::Start, Self::Height(e)) => *e <= 1,
+            (Self::Height(e1), Self::Height(e2)) => *e1 + 1 >= *e2,
+            (Self::Height(e), Self::End) => e + 1 >= 26,
             (Self::End, _) => true,
             (_, _) => false,
         }
@@ -207,7 +210,7 @@ impl PartialEq for Elevation {
     fn eq(&self, other: &Self) -> bool {
         match (self, other) {
             (Self::Start, Self::Start) => true,
-            (Self::Elevation(e1), Self::Elevation(e2)) => e1.eq(e2),
+            (Self::Height(e1), Self::Height(e2)) => e1.eq(e2),
             (Self::End, Self::End) => true,
             (_, _) => false,
         }
@@ -216,17 +219,7 @@ impl PartialEq for Elevation {
 
 impl PartialOrd for Elevation {
     fn partial_cmp (&self, other: &Self) -> Option<Ordering> {
-        match (self, other) {
-            (Self::Start, Self::Start) => Some(Ordering::Equal),
-            (Self::Start, _) => Some(Ordering::Less),
-            (_, Self::Start) => Some(Ordering::Greater),
-
-            (Self::Elevation(e1), Self::Elevation(e2)) => e1.partial_cmp(e2),
-
-            (Self::End, Self::End) => Some(Ordering::Equal),
-            (_, Self::End) => Some(Ordering::Less),
-            (Self::End, _) => Some(Ordering::Greater),
-        }
+        Some(self.cmp(other))
     }
 }
 
@@ -239,7 +232,7 @@ impl Ord for Elevation {
             (Self::Start, _) => Ordering::Less,
             (_, Self::Start) => Ordering::Greater,
 
-            (Self::Elevation(e1), Self::Elevation(e2)) => e1.cmp(e2),
+            (Self::Height(e1), Self::Height(e2)) => e1.cmp(e2),
 
             (Self::End, Self::End) => Ordering::Equal,
             (_, Self::End) => Ordering::Less,
@@ -253,7 +246,52 @@ impl From<char> for Elevation {
         match value {
             'S' => Elevation::Start,
             'E' => Elevation::End,
-            _ => Elevation::Elevation(value as u8 - 'a' as u8),
+            _ => Elevation::Height(value as u8 - b'a'),
         }
     }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "Sabqponm
+abcryxxl
+accszExk
+acctuvwj
+abdefghi";
+
+    #[test]
+    fn example_part_1() {
+        let input = Solution::parse(EXAMPLE).unwrap();
+        assert_eq!(Solution::solve_part_1(&input), Ok(Answer::Int(31)));
+    }
+
+    #[test]
+    fn example_part_2() {
+        let input = Solution::parse(EXAMPLE).unwrap();
+        assert_eq!(Solution::solve_part_2(&input), Ok(Answer::Int(29)));
+    }
+
+    #[test]
+    fn elevation_navigation() {
+        let (a, b, c) = (Elevation::from('a'), Elevation::from('b'), Elevation::from('c'));
+        assert!(a.can_navigate(&b));
+        assert!(!a.can_navigate(&c));
+        assert!(c.can_navigate(&a));
+
+        assert!(Elevation::from('S').can_navigate(&b));
+        assert!(Elevation::from('z').can_navigate(&Elevation::from('E')));
+        assert!(!Elevation::from('x').can_navigate(&Elevation::from('E')));
+    }
+
+    #[test]
+    fn bad_chart() {
+        let input = EXAMPLE.replace('E', "?");
+        let e = Solution::parse(&input).unwrap_err().locate(&input);
+        assert_eq!((e.line, e.column, e.text.as_str()), (Some(3), Some(6), "?"));
+
+        assert!(Solution::parse("Sab\nabc").is_err());
+        assert!(Solution::solve_part_1(&Solution::parse("SazE").unwrap()).is_err());
+    }
 }
\ No newline at end of file
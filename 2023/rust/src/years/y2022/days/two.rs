@@ -0,0 +1,207 @@
+use crate::{error::InputError, solver::{Answer, Solver}};
+
+trait FromABC: Sized {
+    fn from_abc(code: &str) -> Result<Self, InputError>;
+}
+
+trait FromXYZ {
+    fn from_xyz(code: &Xyz) -> Self;
+}
+
+/// The second column of the guide, whose meaning differs between the parts.
+#[derive(Debug)]
+pub enum Xyz {
+    X,
+    Y,
+    Z,
+}
+
+impl Xyz {
+    fn parse(code: &str) -> Result<Self, InputError> {
+        match code {
+            "X" => Ok(Xyz::X),
+            "Y" => Ok(Xyz::Y),
+            "Z" => Ok(Xyz::Z),
+            _ => Err(InputError::new("invalid player code", code)),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub enum Shape {
+    Rock,
+    Paper,
+    Scissors,
+}
+
+impl Shape {
+    fn score(&self) -> u32 {
+        match self {
+            Shape::Rock => 1,
+            Shape::Paper => 2,
+            Shape::Scissors => 3,
+        }
+    }
+
+    fn from_opponent_and_outcome(opponent: &Shape, out: &Outcome) -> Shape {
+        match (opponent, out) {
+            (_, Outcome::Draw) => *opponent,
+            (Shape::Rock, Outcome::Lose) => Shape::Scissors,
+            (Shape::Rock, Outcome::Win) => Shape::Paper,
+            (Shape::Paper, Outcome::Lose) => Shape::Rock,
+            (Shape::Paper, Outcome::Win) => Shape::Scissors,
+            (Shape::Scissors, Outcome::Lose) => Shape::Paper,
+            (Shape::Scissors, Outcome::Win) => Shape::Rock,
+        }
+    }
+}
+
+impl FromABC for Shape {
+    fn from_abc(code: &str) -> Result<Shape, InputError> {
+        match code {
+            "A" => Ok(Shape::Rock),
+            "B" => Ok(Shape::Paper),
+            "C" => Ok(Shape::Scissors),
+            _ => Err(InputError::new("invalid opponent code", code)),
+        }
+    }
+}
+
+impl FromXYZ for Shape {
+    fn from_xyz(code: &Xyz) -> Shape {
+        match code {
+            Xyz::X => Shape::Rock,
+            Xyz::Y => Shape::Paper,
+            Xyz::Z => Shape::Scissors,
+        }
+    }
+}
+
+enum Outcome {
+    Lose,
+    Draw,
+    Win,
+}
+
+impl Outcome {
+    fn score(&self) -> u32 {
+        match self {
+            Outcome::Lose => 0,
+            Outcome::Draw => 3,
+            Outcome::Win => 6,
+        }
+    }
+
+    fn from_shapes(opponenet: &Shape, player: &Shape) -> Self {
+        match (opponenet, player) {
+            (Shape::Rock, Shape::Paper) => Outcome::Win,
+            (Shape::Rock, Shape::Scissors) => Outcome::Lose,
+            (Shape::Paper, Shape::Rock) => Outcome::Lose,
+            (Shape::Paper, Shape::Scissors) => Outcome::Win,
+            (Shape::Scissors, Shape::Rock) => Outcome::Win,
+            (Shape::Scissors, Shape::Paper) => Outcome::Lose,
+            (_, _) => Outcome::Draw,
+        }
+    }
+}
+
+impl FromXYZ for Outcome {
+    fn from_xyz(code: &Xyz) -> Outcome {
+        match code {
+            Xyz::X => Outcome::Lose,
+            Xyz::Y => Outcome::Draw,
+            Xyz::Z => Outcome::Win,
+        }
+    }
+}
+
+pub struct Solution;
+
+impl Solver for Solution {
+    type Input = Vec<(Shape, Xyz)>;
+
+    fn parse(input: &str) -> Result<Self::Input, InputError> {
+        strategy_guide(input)
+    }
+
+    fn solve_part_1(guide: &Self::Input) -> Result<Answer, InputError> {
+        let mut sum = 0;
+        for (opponent, code) in guide {
+            let player = Shape::from_xyz(code);
+            let outcome = Outcome::from_shapes(opponent, &player);
+            sum += player.score() + outcome.score();
+        }
+
+        Ok(sum.into())
+    }
+
+    fn solve_part_2(guide: &Self::Input) -> Result<Answer, InputError> {
+        let mut sum = 0;
+        for (opponent, code) in guide {
+            let outcome = Outcome::from_xyz(code);
+            sum += Shape::from_opponent_and_outcome(opponent, &outcome).score() + outcome.score();
+        }
+
+        Ok(sum.into())
+    }
+}
+
+struct Round<'a> {
+    abc: &'a str,
+    xyz: &'a str,
+}
+
+impl<'a> Round<'a> {
+    fn parse(s: &'a str) -> Result<Self, InputError> {
+        match s.split_once(' ') {
+            Some((abc, xyz)) => Ok(Round{abc, xyz}),
+            None => Err(InputError::new("expected two codes separated by a space", s)),
+        }
+    }
+}
+
+fn strategy_guide<T: FromABC> (contents: &str) -> Result<Vec<(T, Xyz)>, InputError> {
+    let mut v = Vec::new();
+
+    for line in contents.lines() {
+        if line.is_empty() {
+            continue
+        }
+
+        let round = Round::parse(line)?;
+        v.push((T::from_abc(round.abc)?, Xyz::parse(round.xyz)?));
+    }
+
+    Ok(v)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "A Y
+B X
+C Z";
+
+    #[test]
+    fn example_part_1() {
+        let input = Solution::parse(EXAMPLE).unwrap();
+        assert_eq!(Solution::solve_part_1(&input), Ok(Answer::Int(15)));
+    }
+
+    #[test]
+    fn example_part_2() {
+        let input = Solution::parse(EXAMPLE).unwrap();
+        assert_eq!(Solution::solve_part_2(&input), Ok(Answer::Int(12)));
+    }
+
+    #[test]
+    fn bad_codes() {
+        let input = "A Y\nB W\n";
+        let e = Solution::parse(input).unwrap_err().locate(input);
+        assert_eq!((e.line, e.column, e.text.as_str()), (Some(2), Some(3), "W"));
+
+        assert!(Solution::parse("AY").is_err());
+        assert!(Solution::parse("D X").is_err());
+    }
+}
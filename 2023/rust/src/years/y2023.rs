@@ -0,0 +1 @@
+pub mod days;
\ No newline at end of file
@@ -0,0 +1 @@
+crate::days! {}
\ No newline at end of file